@@ -23,6 +23,7 @@ mod raw_processing;
 mod tagging;
 mod tagging_utils;
 mod tethering;
+mod tethering_utils;
 
 use log;
 use std::collections::{HashMap, hash_map::DefaultHasher};
@@ -3146,6 +3147,90 @@ fn main() {
             tethering::tether_set_download_folder,
             tethering::tether_get_config_choices,
             tethering::tether_set_config_value,
+            tethering::tether_lock_focus,
+            tethering::tether_unlock_focus,
+            tethering::tether_stop_all,
+            tethering::tether_get_options,
+            tethering::tether_set_options,
+            tethering::tether_cancel_auto_connect,
+            tethering::tether_get_iso_choices,
+            tethering::tether_get_param_key_map,
+            tethering::tether_start_session,
+            tethering::tether_end_session,
+            tethering::tether_trigger_capture,
+            tethering::tether_get_orientation,
+            tethering::tether_apply_preset,
+            tethering::tether_get_exposure_meter,
+            tethering::tether_get_capabilities,
+            tethering::tether_lock_settings,
+            tethering::tether_unlock_settings,
+            tethering::tether_supported_raw_extensions,
+            tethering::tether_backend_available,
+            tethering::tether_get_self_timer,
+            tethering::tether_set_self_timer,
+            tethering::tether_camera_sleep,
+            tethering::tether_camera_wake,
+            tethering::tether_get_buffer_status,
+            tethering::tether_get_exposure_program,
+            tethering::tether_get_lens_info,
+            tethering::tether_capture_async,
+            tethering::tether_list_session_captures,
+            tethering::tether_get_review_settings,
+            tethering::tether_set_image_review,
+            tethering::tether_set_auto_rotate,
+            tethering::tether_capture_native_focus_bracket,
+            tethering::tether_stop_interval,
+            tethering::tether_generate_contact_sheet,
+            tethering::tether_get_aspect_ratio,
+            tethering::tether_set_aspect_ratio,
+            tethering::tether_get_mode_choices,
+            tethering::tether_set_shutter_type,
+            tethering::tether_get_recent_captures,
+            tethering::tether_benchmark_capture,
+            tethering::tether_get_thermal_status,
+            tethering::tether_spot_meter,
+            tethering::tether_list_camera_folder,
+            tethering::tether_download_specific,
+            tethering::tether_get_card_tree,
+            tethering::tether_reset_camera_settings,
+            tethering::tether_apply_default_settings,
+            tethering::tether_capture_calibration_set,
+            tethering::tether_get_quiet_settings,
+            tethering::tether_set_beep,
+            tethering::tether_set_af_assist,
+            tethering::tether_get_connection_history,
+            tethering::tether_set_remote_preview,
+            tethering::tether_dof_preview,
+            tethering::tether_get_exposure_delay,
+            tethering::tether_set_exposure_delay,
+            tethering::tether_download_latest,
+            tethering::tether_verify_param_aliases,
+            tethering::tether_capture_wb_bracket,
+            tethering::tether_get_af_point,
+            tethering::tether_capture_iso_sweep,
+            tethering::tether_get_ptp_property,
+            tethering::tether_set_ptp_property,
+            tethering::tether_capture_auto_exposure,
+            tethering::tether_enumerate_devices,
+            tethering::tether_get_last_thumbnail,
+            tethering::tether_set_capture_destination,
+            tethering::tether_get_backend_info,
+            tethering::tether_capture_pending,
+            tethering::tether_confirm_capture,
+            tethering::tether_discard_capture,
+            tethering::tether_get_time_zone,
+            tethering::tether_set_time_zone,
+            tethering::tether_sync_camera_time,
+            tethering::tether_start_liveview,
+            tethering::tether_stop_liveview,
+            tethering::tether_drive_focus,
+            tethering::tether_autofocus,
+            tethering::tether_start_timelapse,
+            tethering::tether_stop_timelapse,
+            tethering::tether_capture_bracket,
+            tethering::tether_capture_focus_stack,
+            tethering::tether_list_cameras,
+            tethering::tether_connect_network,
         ])
         .build(tauri::generate_context!())
         .expect("error while building tauri application")
@@ -3,17 +3,264 @@
 
 use gphoto2::{Context, Camera};
 use gphoto2::camera::CameraEvent;
+use gphoto2::file::CameraFilePath;
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 use std::sync::Arc;
-use std::time::{Duration, SystemTime};
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicUsize, Ordering};
+use std::time::{Duration, Instant, SystemTime};
 use tokio::sync::Mutex;
 use tauri::{AppHandle, Emitter};
+use uuid::Uuid;
+use base64::{Engine as _, engine::general_purpose};
 
 use image as image_crate;
 use rawler::{rawsource::RawSource, decoders::RawDecodeParams};
 use chrono;
 
+use crate::file_management::{get_cached_or_generate_thumbnail_image, parse_virtual_path};
+use crate::formats;
+use crate::image_processing::ImageMetadata;
+use crate::tagging::COLOR_TAG_PREFIX;
+use crate::tethering_utils::iso::{self, IsoValue};
+use crate::tethering_utils::remote_preview::{self, PreviewFormat, RemotePreview, RemotePreviewSettings};
+use crate::tethering_utils::self_timer::{self, SelfTimerChoice};
+use crate::tethering_utils::{burst_rate, contact_sheet, exposure_target, focus_peaking, sensor_dimensions, xmp};
+
+/// How long a params read is served from cache before going back to the camera
+const PARAMS_CACHE_TTL: Duration = Duration::from_secs(1);
+
+/// Live view refreshes params every N preview frames instead of the monitor polling
+const LIVEVIEW_PARAMS_EVERY_N_FRAMES: u64 = 15;
+
+/// Live view frames between focus peaking overlays; edge detection is too costly for every frame
+const LIVEVIEW_PEAKING_EVERY_N_FRAMES: u64 = 3;
+
+/// Live view frame rate when the caller doesn't pick one, and the range it is clamped to
+const LIVEVIEW_DEFAULT_FPS: f32 = 10.0;
+const LIVEVIEW_MIN_FPS: f32 = 1.0;
+const LIVEVIEW_MAX_FPS: f32 = 30.0;
+
+/// Event wait while live view streams, short so the monitor hands the camera back between frames
+const LIVEVIEW_EVENT_WAIT: Duration = Duration::from_millis(20);
+
+/// Config key aliases tried for each logical param, in order
+const ISO_KEYS: &[&str] = &["iso", "isospeed", "autoiso"];
+const SHUTTER_SPEED_KEYS: &[&str] = &["shutterspeed", "shutter", "shutterspeed2", "exptime", "exposuretime"];
+const APERTURE_KEYS: &[&str] = &["aperture", "f-number", "fnumber", "aperture2"];
+const EXPOSURE_COMPENSATION_KEYS: &[&str] = &["exposurecompensation", "expcomp", "exposurecomp", "exposure"];
+const SHOOTING_MODE_KEYS: &[&str] = &["shootingmode", "capturemode", "capturemode2", "autoexposuremode", "exposuremode", "mode"];
+const WHITE_BALANCE_KEYS: &[&str] = &["whitebalance", "whitebalanceadjust", "whitebalance2", "wb"];
+const FOCUS_MODE_KEYS: &[&str] = &["focusmode", "autofocus", "afmode", "focusmode2"];
+const DRIVE_MODE_KEYS: &[&str] = &["drivemode", "capturemode", "continuous"];
+const METERING_MODE_KEYS: &[&str] = &["meteringmode", "meteringmodedial", "metering"];
+const ASPECT_RATIO_KEYS: &[&str] = &["aspectratio", "imageaspectratio", "aspect"];
+/// Continuous shooting rate on bodies that report it ("12 fps", "High speed 10fps")
+const BURST_RATE_KEYS: &[&str] = &["continuousspeed", "shootingspeed", "burstrate"];
+/// Body clock keys: a UTC clock the body offsets by its own time zone, or a local wall clock
+const CLOCK_UTC_KEYS: &[&str] = &["datetimeutc"];
+const CLOCK_LOCAL_KEYS: &[&str] = &["datetime"];
+const TIMEZONE_KEYS: &[&str] = &["timezone", "timezonesetting", "worldtime"];
+const DST_KEYS: &[&str] = &["dst", "daylightsaving", "daylightsavingtime", "summertime"];
+/// Where the body stores captures: its RAM (deleted once downloaded) or the memory card
+const CAPTURE_TARGET_KEYS: &[&str] = &["capturetarget"];
+/// Dedicated shutter type settings; bodies without one may offer silent drive modes instead
+const SHUTTER_TYPE_KEYS: &[&str] = &["shuttertype", "silentmode", "silent", "electronicshutter"];
+const BATTERY_LEVEL_KEYS: &[&str] = &["batterylevel"];
+const IMAGES_REMAINING_KEYS: &[&str] = &["remainingimages"];
+const FOCUS_CONFIRM_KEYS: &[&str] = &["focusconfirm", "focuslock", "afconfirm"];
+const EXPOSURE_METER_KEYS: &[&str] = &["lightmeter", "exposuremeter", "exposurelevelindicator"];
+/// Remotely movable AF area (Nikon, "XxY" in live view image pixels); spot metering follows the AF point
+const AF_AREA_KEYS: &[&str] = &["changeafarea"];
+/// Selected AF point or zone, where the body reports it
+const AF_POINT_KEYS: &[&str] = &["focuspoint", "afpoint", "focusarea", "autofocusarea", "afarea"];
+const ORIENTATION_KEYS: &[&str] = &["orientation", "eosorientation", "cameraorientation"];
+
+/// Config group where the PTP driver lists device properties it has no named widget for,
+/// each keyed by its property code in hex ("d1a6")
+const PTP_PROPERTY_GROUP: &str = "other";
+
+/// Logical param names paired with their aliases, used for diagnostics
+const PARAM_KEY_ALIASES: &[(&str, &[&str])] = &[
+    ("iso", ISO_KEYS),
+    ("shutterSpeed", SHUTTER_SPEED_KEYS),
+    ("aperture", APERTURE_KEYS),
+    ("exposureCompensation", EXPOSURE_COMPENSATION_KEYS),
+    ("shootingMode", SHOOTING_MODE_KEYS),
+    ("whiteBalance", WHITE_BALANCE_KEYS),
+    ("focusMode", FOCUS_MODE_KEYS),
+    ("driveMode", DRIVE_MODE_KEYS),
+    ("meteringMode", METERING_MODE_KEYS),
+    ("aspectRatio", ASPECT_RATIO_KEYS),
+    ("batteryLevel", BATTERY_LEVEL_KEYS),
+    ("imagesRemaining", IMAGES_REMAINING_KEYS),
+];
+
+/// Config keys carrying the body's serial number, which identifies it across USB ports
+const SERIAL_NUMBER_KEYS: &[&str] = &["serialnumber", "eosserialnumber"];
+
+/// Params every body must resolve, with label fragments to search the config tree for when they don't
+const REQUIRED_PARAM_LABELS: &[(&str, &[&str])] = &[
+    ("iso", &["iso"]),
+    ("shutterSpeed", &["shutter speed", "exposure time", "shutter"]),
+    ("aperture", &["aperture", "f-number"]),
+];
+
+/// Config keys that stay readable while the body is in playback/review mode
+const STATUS_KEYS: &[&str] = &["cameramodel", "manufacturer", "serialnumber", "batterylevel"];
+
+/// How long a params read waits for a reviewing body to return to capture mode
+const CAPTURE_MODE_WAIT: Duration = Duration::from_secs(2);
+
+/// How long autofocus may take to lock before a capture is abandoned
+const AUTOFOCUS_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Budget for a whole capture (autofocus, shutter and download) before giving up on the camera
+const CAPTURE_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Pause after a focus drive step so the lens has settled before the next frame
+const FOCUS_DRIVE_SETTLE: Duration = Duration::from_millis(300);
+
+/// gphoto2 port prefix for cameras reached over the network (PTP/IP)
+const PTPIP_PORT_PREFIX: &str = "ptpip:";
+
+/// Model name libgphoto2 uses for PTP/IP bodies it hasn't identified yet
+const PTPIP_GENERIC_MODEL: &str = "PTP/IP Camera";
+
+/// Config keys for a remote factory/settings reset
+const FACTORY_RESET_KEYS: &[&str] = &["factoryreset", "resetallsettings", "resetsettings", "settingsreset"];
+
+/// Built-in defaults for bodies without a remote reset: param aliases and preferred choices,
+/// matched case-insensitively in order
+const SANE_DEFAULTS: &[(&[&str], &[&str])] = &[
+    (EXPOSURE_COMPENSATION_KEYS, &["0", "0.0"]),
+    (ISO_KEYS, &["auto", "auto iso"]),
+    (WHITE_BALANCE_KEYS, &["auto", "automatic", "awb"]),
+    (DRIVE_MODE_KEYS, &["single", "single frame", "single shot", "single-frame shooting"]),
+    (METERING_MODE_KEYS, &["evaluative", "matrix", "multi", "multi-segment", "multi spot"]),
+    (FOCUS_MODE_KEYS, &["one shot", "af-s", "single"]),
+];
+
+/// Config keys that put the body into its power-save state
+const POWER_SAVE_KEYS: &[&str] = &["powersavemode", "powerdown", "sleep"];
+
+/// Time a sleeping body needs to answer again; interval shooting wakes it this early
+const CAMERA_WAKE_LATENCY: Duration = Duration::from_secs(3);
+
+/// Marker written next to an in-progress `.part` download, holding the card folder and name
+/// Left behind by a crash, it lets the next connect re-download the frame from the card
+const PART_SOURCE_SUFFIX: &str = ".part.source";
+
+/// Download attempts when verification finds the local file doesn't match the card
+const DOWNLOAD_VERIFY_ATTEMPTS: u32 = 3;
+
+/// Consecutive event timeouts without any NewFile before falling back to filesystem polling (~1 min)
+const EVENT_TIMEOUTS_BEFORE_FS_POLL: u32 = 200;
+
+/// How often the filesystem fallback lists the card for new files
+const FS_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Config keys for the post-shot image review on the body's screen
+const IMAGE_REVIEW_KEYS: &[&str] = &["imagereview", "reviewtime", "recordingreview"];
+
+/// Config keys for automatic rotation of portrait shots
+const AUTO_ROTATE_KEYS: &[&str] = &["autorotate", "autorotation", "rotationflag"];
+
+/// Config keys for the exposure delay (shutter fires this long after the release)
+const EXPOSURE_DELAY_KEYS: &[&str] = &["exposuredelay", "exposuredelaymode", "exposuredelaymodesetting"];
+
+/// Config keys for the depth-of-field preview (stopping down to the set aperture)
+const DOF_PREVIEW_KEYS: &[&str] = &["depthoffield", "dofpreview", "eosdofpreview"];
+
+/// Config keys for the focus confirmation beep
+const BEEP_KEYS: &[&str] = &["beep", "beepmode", "audiosignal", "soundsignal"];
+
+/// Config keys for the AF-assist lamp
+const AF_ASSIST_KEYS: &[&str] = &["afassist", "afassistlamp", "afassistbeam", "afbeam"];
+
+/// Config keys for in-camera focus bracketing (Canon "focus bracketing", Nikon "focus shift")
+const FOCUS_BRACKET_ENABLE_KEYS: &[&str] = &["focusbracketing", "focusshift", "focusshiftshooting"];
+const FOCUS_BRACKET_SHOTS_KEYS: &[&str] = &["focusbracketingshots", "focusbracketingnumberofshots", "focusshiftnumberofshots"];
+const FOCUS_BRACKET_STEP_KEYS: &[&str] = &["focusbracketingstep", "focusbracketingfocusincrement", "focusshiftstepwidth"];
+
+/// Config keys carrying the attached lens name
+const LENS_NAME_KEYS: &[&str] = &["lensname", "lens", "lensid"];
+
+/// Config keys carrying the lens focal range (Nikon reports both ends)
+const MIN_FOCAL_LENGTH_KEYS: &[&str] = &["minfocallength", "focallengthmin"];
+const MAX_FOCAL_LENGTH_KEYS: &[&str] = &["maxfocallength", "focallengthmax"];
+
+/// Config keys some bodies use to report how many frames still fit in the buffer
+const BUFFER_KEYS: &[&str] = &["bufferdepth", "buffersize", "availableshots"];
+
+/// Connect/disconnect events kept in memory for get_connection_history
+const CONNECTION_HISTORY_LIMIT: usize = 500;
+
+/// Consecutive "busy" capture failures after which the buffer is assumed full
+const BUFFER_FULL_BUSY_THRESHOLD: u32 = 2;
+
+/// Config keys bodies use to warn about internal temperature
+const THERMAL_KEYS: &[&str] = &["temperaturewarning", "hightemperaturewarning", "overheatwarning", "overheat", "thermalstatus"];
+
+/// How often the monitor checks the thermal warning while connected
+const THERMAL_CHECK_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Size budget for the preview capture_pending returns
+const PENDING_PREVIEW_BYTES: usize = 400_000;
+
+/// Preview/adjust rounds capture_auto_exposure runs before taking the frame anyway
+const AUTO_EXPOSURE_MAX_ITERATIONS: u32 = 5;
+
+/// Corrections smaller than this (in stops) count as on target
+const AUTO_EXPOSURE_TOLERANCE_EV: f32 = 1.0 / 6.0;
+
+/// Config keys carrying the in-camera self-timer; dedicated timer widgets first, then drive modes
+const SELF_TIMER_KEYS: &[&str] = &["selftimer", "selftimerdelay", "capturemode", "drivemode"];
+
+/// Config keys bodies use for the still image format (RAW, JPEG, RAW+JPEG)
+const IMAGE_FORMAT_KEYS: &[&str] = &["imageformat", "imagequality", "imageformatsd", "imageformatcf"];
+
+/// Monitor reconnect backoff bounds after repeated auto-connect failures
+const AUTO_CONNECT_BASE_INTERVAL: Duration = Duration::from_millis(500);
+const AUTO_CONNECT_MAX_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Typed tethering failures
+/// Commands return them as their message text, so the kind is carried by the fixed start of the
+/// message ("Tethering backend unavailable:", "Busy:", "Camera is in playback mode"); the UI can
+/// also ask tether_backend_available up front instead of waiting for a failing command.
+#[derive(Debug, Clone)]
+pub enum TetherError {
+    /// libgphoto2 (or its camera drivers) could not be loaded
+    BackendUnavailable(String),
+    /// Another exclusive camera operation is running
+    Busy(String),
+    /// The body is in playback/review mode and hides its capture settings
+    NotInCaptureMode,
+}
+
+impl std::fmt::Display for TetherError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TetherError::BackendUnavailable(detail) => write!(
+                f,
+                "Tethering backend unavailable: libgphoto2 not installed or failed to load ({})",
+                detail
+            ),
+            TetherError::Busy(operation) => write!(f, "Busy: camera is in use by {}", operation),
+            TetherError::NotInCaptureMode => write!(
+                f,
+                "Camera is in playback mode; capture settings return once it is back in shooting mode"
+            ),
+        }
+    }
+}
+
+impl From<TetherError> for String {
+    fn from(error: TetherError) -> Self {
+        error.to_string()
+    }
+}
+
 /// Current camera parameters with extended support
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -23,275 +270,3860 @@ pub struct CameraParams {
     pub aperture: String,
     pub exposure_compensation: Option<String>,
     pub shooting_mode: Option<String>,
+    /// The physical mode dial governs the shooting mode, so it can't be set remotely
+    pub shooting_mode_locked: bool,
     pub white_balance: Option<String>,
     pub focus_mode: Option<String>,
     pub drive_mode: Option<String>,
     pub metering_mode: Option<String>,
+    /// In-camera aspect ratio (e.g. "1:1"); RAW files still record the full sensor
+    pub aspect_ratio: Option<String>,
     pub battery_level: Option<f32>,
+    /// Raw battery reading on bodies that report discrete states ("Full", "Half", "Low")
+    pub battery_state: Option<String>,
     pub images_remaining: Option<u32>,
+    /// Whether the body currently reports focus as confirmed/locked.
+    /// Only meaningful during live view (Canon reports it there); None otherwise.
+    pub focus_confirmed: Option<bool>,
+    /// Metered over/under exposure in EV (-3..+3); only meaningful in manual mode
+    pub exposure_meter: Option<f32>,
+    /// `shooting_mode` normalized across brands; None for scene modes and unknown strings
+    pub exposure_program: Option<ExposureProgram>,
+    /// None when the body has neither a shutter type setting nor silent drive modes
+    pub shutter_type: Option<ShutterType>,
+    /// None when the body has no capture target setting
+    pub capture_destination: Option<CaptureDestination>,
     pub model: String,
+    /// Identifies the body across reconnects, which may land on a different port
+    pub serial_number: Option<String>,
     pub port: String,
+    /// Negotiated USB link (e.g. "USB 2.0 (480 Mbps)"); None where the OS doesn't expose it
+    pub connection_speed: Option<String>,
 }
 
-/// Camera capture result - supports both single and dual capture (RAW+JPG)
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Video container extensions cameras write alongside stills
+const VIDEO_EXTENSIONS: &[&str] = &["mov", "mp4", "avi", "mts", "m2ts", "crm"];
+
+/// What kind of file(s) a capture produced
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct CaptureResult {
-    pub file_path: String,
-    pub raw_path: Option<String>,  // RAW file path (if captured separately)
-    pub jpg_path: Option<String>,  // JPG file path (if captured separately)
-    pub preview_path: Option<String>,
-    pub width: u32,
-    pub height: u32,
+pub enum CaptureFormat {
+    Raw,
+    Jpeg,
+    RawPlusJpeg,
+    Video,
+    Other,
 }
 
-/// Global camera service state
-pub struct CameraService {
-    pub camera: Arc<Mutex<Option<Camera>>>,
-    capture_dir: PathBuf,
-    /// Current folder for downloading images from camera button presses
-    current_download_folder: Arc<Mutex<Option<String>>>,
-    /// Cached dimensions for faster capture (model -> (width, height))
-    cached_dimensions: Arc<Mutex<std::collections::HashMap<String, (u32, u32)>>>,
-}
+impl CaptureFormat {
+    /// Classify the downloaded file(s) of a capture by extension
+    pub fn from_paths(paths: &[&str]) -> Self {
+        let has_raw = paths.iter().any(|p| formats::is_raw_file(p));
+        let has_jpeg = paths.iter().any(|p| {
+            let lower = p.to_lowercase();
+            lower.ends_with(".jpg") || lower.ends_with(".jpeg")
+        });
+        let has_video = paths.iter().any(|p| {
+            std::path::Path::new(p)
+                .extension()
+                .and_then(|e| e.to_str())
+                .map_or(false, |e| VIDEO_EXTENSIONS.contains(&e.to_lowercase().as_str()))
+        });
 
-impl CameraService {
-    /// Create a new camera service
-    pub fn new(capture_dir: PathBuf) -> Self {
-        Self {
-            camera: Arc::new(Mutex::new(None)),
-            capture_dir,
-            current_download_folder: Arc::new(Mutex::new(None)),
-            cached_dimensions: Arc::new(Mutex::new(std::collections::HashMap::new())),
+        match (has_raw, has_jpeg) {
+            (true, true) => CaptureFormat::RawPlusJpeg,
+            (true, false) => CaptureFormat::Raw,
+            (false, true) => CaptureFormat::Jpeg,
+            _ if has_video => CaptureFormat::Video,
+            _ => CaptureFormat::Other,
         }
     }
+}
 
-    /// Extract real file extension from camera filename
-    /// Handles formats like "capt0000.jpg", "IMG_1234.CR3", "CRW_0001.JPG", etc.
-    fn extract_file_extension(original_name: &str) -> String {
-        // Convert to lowercase for easier matching
-        let name_lower = original_name.to_lowercase();
-
-        // List of known RAW extensions
-        let raw_extensions = ["cr3", "cr2", "nef", "arw", "dng", "raf", "orf", "pef", "rw2", "srw", "crw"];
-
-        // Split by dots and process from right to left (last extension is the real one)
-        let parts: Vec<&str> = name_lower.rsplit('.').collect();
-
-        for (i, part) in parts.iter().enumerate() {
-            if part.is_empty() {
-                continue;
-            }
+/// Camera body orientation, as clockwise rotation needed to display upright
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Orientation {
+    Normal,
+    Rotate90,
+    Rotate180,
+    Rotate270,
+}
 
-            // Skip purely numeric parts or known camera internal prefixes
-            // capt0000, 0000, etc. are camera's internal naming, not real extensions
-            if part.chars().all(|c| c.is_numeric()) || part.starts_with("capt") {
-                continue;
-            }
+impl Orientation {
+    /// Map an EXIF orientation tag value; mirrored variants map to their rotation
+    pub fn from_exif(value: u32) -> Option<Self> {
+        match value {
+            1 | 2 => Some(Orientation::Normal),
+            3 | 4 => Some(Orientation::Rotate180),
+            5 | 6 => Some(Orientation::Rotate90),
+            7 | 8 => Some(Orientation::Rotate270),
+            _ => None,
+        }
+    }
 
-            // Check if it's a known extension
-            if *part == "jpg" || *part == "jpeg" || raw_extensions.contains(part) {
-                return if *part == "jpeg" {
-                    "jpg".to_string()
+    /// Parse a camera config value like "90'", "270" or "Horizontal"
+    fn from_config_value(value: &str) -> Option<Self> {
+        let digits: String = value.chars().filter(|c| c.is_ascii_digit()).collect();
+        match digits.parse::<u32>() {
+            Ok(0) => Some(Orientation::Normal),
+            Ok(90) => Some(Orientation::Rotate90),
+            Ok(180) => Some(Orientation::Rotate180),
+            Ok(270) => Some(Orientation::Rotate270),
+            Ok(_) => None,
+            Err(_) => {
+                let lower = value.to_lowercase();
+                if lower.contains("horizontal") || lower.contains("landscape") {
+                    Some(Orientation::Normal)
                 } else {
-                    part.to_string()
-                };
-            }
-
-            // If we've gone past the first part (real extension) and hit something unknown,
-            // and the previous parts were all camera-specific, return jpg as default
-            if i > 0 {
-                return "jpg".to_string();
+                    None
+                }
             }
         }
-
-        // Default to jpg if we can't determine
-        "jpg".to_string()
     }
+}
 
-    /// Check if a file path is a RAW file
-    fn is_raw_file(path: &str) -> bool {
-        let path_lower = path.to_lowercase();
-        path_lower.ends_with(".cr3")
-            || path_lower.ends_with(".cr2")
-            || path_lower.ends_with(".nef")
-            || path_lower.ends_with(".arw")
-            || path_lower.ends_with(".dng")
-            || path_lower.ends_with(".raf")
-            || path_lower.ends_with(".orf")
-            || path_lower.ends_with(".pef")
-            || path_lower.ends_with(".rw2")
-            || path_lower.ends_with(".srw")
-    }
+/// Exposure program / mode dial position, normalized across brands
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ExposureProgram {
+    Manual,
+    Aperture,
+    Shutter,
+    Program,
+    Bulb,
+    Auto,
+}
 
-    /// Get image dimensions, supporting both regular formats and RAW files
-    fn get_image_dimensions(file_path: &PathBuf) -> Option<(u32, u32)> {
-        // First try with image crate (for JPEG, PNG, etc.)
-        if let Ok(dim) = image_crate::image_dimensions(file_path) {
-            return Some(dim);
+impl ExposureProgram {
+    /// Map a shooting mode string ("Av", "aperture-priority", "A", "Tv", "P*", ...)
+    pub fn from_mode(mode: &str) -> Option<Self> {
+        let lower = mode.trim().trim_end_matches('*').to_lowercase();
+        match lower.as_str() {
+            "m" => return Some(ExposureProgram::Manual),
+            "a" | "av" => return Some(ExposureProgram::Aperture),
+            "s" | "tv" => return Some(ExposureProgram::Shutter),
+            "p" => return Some(ExposureProgram::Program),
+            "b" => return Some(ExposureProgram::Bulb),
+            _ => {}
         }
 
-        // If that fails and it's a RAW file, try with rawler
-        if Self::is_raw_file(&file_path.to_string_lossy()) {
-            if let Ok(data) = std::fs::read(file_path) {
-                let source = RawSource::new_from_slice(&data);
-                if let Ok(decoder) = rawler::get_decoder(&source) {
-                    if let Ok(raw_image) = decoder.raw_image(&source, &RawDecodeParams::default(), false) {
-                        let w = raw_image.width as u32;
-                        let h = raw_image.height as u32;
-                        return Some((w, h));
-                    }
-                }
-            }
+        // Longer names; "Program Auto" and "Shutter Priority Auto" must match before plain auto
+        if lower.contains("bulb") {
+            Some(ExposureProgram::Bulb)
+        } else if lower.contains("manual") {
+            Some(ExposureProgram::Manual)
+        } else if lower.contains("aperture") {
+            Some(ExposureProgram::Aperture)
+        } else if lower.contains("shutter") {
+            Some(ExposureProgram::Shutter)
+        } else if lower.contains("program") {
+            Some(ExposureProgram::Program)
+        } else if lower.contains("auto") || lower == "green" {
+            Some(ExposureProgram::Auto)
+        } else {
+            None
         }
-
-        None
     }
+}
 
-    /// Helper to get a RadioWidget value with multiple key attempts
-    fn get_radio_value(camera: &Camera, keys: &[&str]) -> Option<String> {
-        for key in keys {
-            if let Ok(widget) = camera.config_key::<gphoto2::widget::RadioWidget>(key).wait() {
-                return Some(widget.choice().to_string());
-            }
+/// Where remote captures end up
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CaptureDestination {
+    /// Left on the card; capture_and_download fires without downloading, camera-button shots aren't downloaded
+    CardOnly,
+    /// Kept in the body's RAM until downloaded, so no card copy remains
+    PcOnly,
+    /// Written to the card and downloaded; the card copy is never deleted
+    Both,
+}
+
+impl CaptureDestination {
+    /// Map a capture target choice; "Memory card" is Both unless card-only was chosen in the app,
+    /// since the body itself can't tell the two apart
+    fn from_choice(choice: &str, card_only: bool) -> Option<Self> {
+        let lower = choice.to_lowercase();
+        let card = lower.contains("card");
+        let ram = lower.contains("ram");
+        match (card, ram) {
+            (true, false) if card_only => Some(CaptureDestination::CardOnly),
+            (true, _) => Some(CaptureDestination::Both),
+            (false, true) => Some(CaptureDestination::PcOnly),
+            (false, false) => None,
         }
-        None
     }
+}
 
-    /// Connect to the first available camera
-    pub async fn connect_camera(&self, app: AppHandle) -> std::result::Result<CameraParams, String> {
-        let (camera, _model, _port) = tokio::task::spawn_blocking(|| {
-            let context = Context::new().map_err(|e| format!("Failed to create context: {}", e))?;
-
-            let camera = context.autodetect_camera()
-                .wait()
-                .map_err(|e| format!("Failed to autodetect: {}", e))?;
-
-            // Get camera info
-            let abilities = camera.abilities();
-            let model = abilities.model().to_string();
-            let port = "usb".to_string();
+/// Shutter mechanism used for stills
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ShutterType {
+    Mechanical,
+    /// Electronic first curtain, mechanical second curtain
+    ElectronicFirstCurtain,
+    /// Fully electronic ("silent") shutter
+    Electronic,
+}
 
-            Ok::<(Camera, String, String), String>((camera, model, port))
-        })
-        .await
-        .map_err(|e| format!("Task join error: {}", e))??;
+impl ShutterType {
+    /// Map a shutter type choice ("Mechanical", "EFCS", "Electronic Shutter", "Silent", ...)
+    pub fn from_choice(choice: &str) -> Option<Self> {
+        let lower = choice.trim().to_lowercase();
+        match lower.as_str() {
+            "mech" | "ms" => return Some(ShutterType::Mechanical),
+            "efc" | "efcs" => return Some(ShutterType::ElectronicFirstCurtain),
+            "es" => return Some(ShutterType::Electronic),
+            _ => {}
+        }
 
-        *self.camera.lock().await = Some(camera);
+        // "Electronic front curtain" and "Mechanical + Electronic" must match before plain electronic
+        if lower.contains("front") || lower.contains("first") || lower.contains("curtain") {
+            Some(ShutterType::ElectronicFirstCurtain)
+        } else if lower.contains("mech") && (lower.contains("elec") || lower.contains('+')) {
+            None
+        } else if lower.contains("mech") || lower == "off" {
+            Some(ShutterType::Mechanical)
+        } else if lower.contains("elec") || lower.contains("silent") || lower == "on" {
+            Some(ShutterType::Electronic)
+        } else {
+            None
+        }
+    }
+}
 
-        // Get initial parameters
-        let params = self.get_camera_params_internal().await?;
+/// Which way manual focus drive moves the lens
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum FocusDirection {
+    Near,
+    Far,
+}
 
-        // Emit connected event
-        app.emit("camera:status", "Connected").ok();
-        eprintln!("{} [Camera] Connected to {}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), params.model);
+/// Size of one manual focus drive step
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum FocusStep {
+    Small,
+    Medium,
+    Large,
+}
 
-        Ok(params)
+impl FocusStep {
+    /// Step number in Canon's "Near 1".."Far 3" choices
+    fn level(&self) -> u8 {
+        match self {
+            FocusStep::Small => 1,
+            FocusStep::Medium => 2,
+            FocusStep::Large => 3,
+        }
     }
 
-    /// Disconnect from current camera
-    pub async fn disconnect_camera(&self, app: AppHandle) -> std::result::Result<(), String> {
-        *self.camera.lock().await = None;
-        app.emit("camera:status", "Disconnected").ok();
-        eprintln!("{} [Camera] Disconnected by user", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"));
-        Ok(())
+    /// Drive amount on bodies with a signed range widget (Nikon), clamped to the range
+    fn range_amount(&self) -> f32 {
+        match self {
+            FocusStep::Small => 10.0,
+            FocusStep::Medium => 100.0,
+            FocusStep::Large => 500.0,
+        }
     }
+}
 
-    /// Get current camera parameters (internal version with minimal logging)
-    async fn get_camera_params_internal(&self) -> std::result::Result<CameraParams, String> {
-        let camera = {
-            let camera_guard = self.camera.lock().await;
-            camera_guard
-                .as_ref()
-                .ok_or("No camera connected")?
-                .clone()
-        };
+/// Type of astro calibration frame
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CalibrationKind {
+    /// Lens capped, same exposure as the lights
+    Dark,
+    /// Evenly lit target at the imaging focus and aperture
+    Flat,
+    /// Lens capped, shortest shutter speed
+    Bias,
+}
 
-        let params = tokio::task::spawn_blocking(move || {
-            let abilities = camera.abilities();
-            let model = abilities.model().to_string();
-            let port = "usb".to_string();
+impl CalibrationKind {
+    /// Subfolder below `calibration/` the frames are stored in
+    pub fn folder_name(&self) -> &'static str {
+        match self {
+            CalibrationKind::Dark => "darks",
+            CalibrationKind::Flat => "flats",
+            CalibrationKind::Bias => "bias",
+        }
+    }
 
-            // Get ISO - try multiple key names
-            let iso = Self::get_radio_value(&camera, &["iso", "isospeed", "autoiso"])
-                .ok_or_else(|| "Failed to get ISO - camera may be disconnected")?;
+    /// Tag written into the editor's sidecar for each frame
+    pub fn tag(&self) -> &'static str {
+        match self {
+            CalibrationKind::Dark => "dark frame",
+            CalibrationKind::Flat => "flat frame",
+            CalibrationKind::Bias => "bias frame",
+        }
+    }
+}
 
-            // Get shutter speed
-            let shutter_speed = Self::get_radio_value(&camera, &[
-                "shutterspeed", "shutter", "shutterspeed2", "exptime", "exposuretime",
-            ]).ok_or_else(|| "Failed to get shutter speed - camera may be disconnected")?;
+/// Camera capture result - supports both single and dual capture (RAW+JPG)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CaptureResult {
+    pub file_path: String,
+    pub raw_path: Option<String>,  // RAW file path (if captured separately)
+    pub jpg_path: Option<String>,  // JPG file path (if captured separately)
+    pub preview_path: Option<String>,
+    pub width: u32,
+    pub height: u32,
+    pub format: CaptureFormat,
+    /// Path of the capture before this one, for before/after comparison
+    pub previous_capture: Option<String>,
+    /// Session the capture belongs to, if one was active
+    pub session_id: Option<String>,
+    /// Body orientation at capture, from the camera or the file's EXIF
+    pub orientation: Option<Orientation>,
+    /// Size of the downloaded file in bytes
+    pub file_size: u64,
+    /// Per-file sizes of a RAW+JPEG pair, set once both halves are on disk (the second download)
+    pub raw_file_size: Option<u64>,
+    pub jpg_file_size: Option<u64>,
+    /// Size the camera reported for the file on the card, to spot truncated downloads
+    pub camera_file_size: Option<u64>,
+    /// BLAKE3 hash of the downloaded file, when download verification is enabled
+    pub checksum: Option<String>,
+    /// Id returned by capture_async, so the UI can match the finished download to its shot
+    pub capture_id: Option<String>,
+    /// Burst/bracket sequence the frame belongs to, for grouping in the filmstrip
+    pub sequence_id: Option<String>,
+    /// In-camera aspect ratio at capture, as a crop hint; width/height stay the full sensor for RAW
+    pub aspect_ratio: Option<String>,
+    /// Set for calibration frames shot by capture_calibration_set
+    pub calibration: Option<CalibrationKind>,
+    /// AF point/zone selected when the frame was downloaded; None where the body doesn't report it
+    pub af_point: Option<String>,
+}
 
-            // Get aperture
-            let aperture = Self::get_radio_value(&camera, &[
-                "aperture", "f-number", "fnumber", "aperture2",
-            ]).ok_or_else(|| "Failed to get aperture - camera may be disconnected")?;
+/// What the connected body can do, probed once per connection
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CameraCapabilities {
+    pub live_view: bool,
+    pub bulb: bool,
+    pub video: bool,
+    pub remote_af: bool,
+    pub manual_focus_drive: bool,
+    pub card_format: bool,
+    pub trigger_capture: bool,
+    /// In-camera focus bracketing; preferred over stepping focus from the host
+    pub native_focus_bracket: bool,
+    /// Rated continuous frame rate, from the body or the model table; None if unknown
+    pub max_fps: Option<f32>,
+}
 
-            // Get other parameters (optional)
-            let exposure_compensation = Self::get_radio_value(&camera, &[
-                "exposurecompensation", "expcomp", "exposurecomp", "exposure",
-            ]);
+/// A single config key/value in a camera preset
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PresetSetting {
+    pub key: String,
+    pub value: String,
+}
 
-            let shooting_mode = Self::get_radio_value(&camera, &[
-                "shootingmode", "capturemode", "capturemode2", "autoexposuremode", "exposuremode", "mode",
-            ]);
+/// Named set of camera settings, applied in order
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CameraPreset {
+    pub name: String,
+    pub settings: Vec<PresetSetting>,
+}
 
-            let white_balance = Self::get_radio_value(&camera, &[
-                "whitebalance", "whitebalanceadjust", "whitebalance2", "wb",
-            ]);
+/// The camera's own self-timer setting and the delays it offers
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SelfTimer {
+    /// Config key the timer lives on (e.g. "selftimer" or "drivemode")
+    pub key: String,
+    /// Current delay in seconds; 0 when the timer is off
+    pub seconds: Option<u32>,
+    pub choices: Vec<SelfTimerChoice>,
+}
 
-            let focus_mode = Self::get_radio_value(&camera, &[
-                "focusmode", "autofocus", "afmode", "focusmode2",
-            ]);
+/// A body setting with its resolved config key and available choices
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigSetting {
+    pub key: String,
+    pub value: String,
+    pub choices: Vec<String>,
+}
 
-            let drive_mode = Self::get_radio_value(&camera, &[
-                "drivemode", "capturemode", "continuous",
-            ]);
+/// Image review and auto-rotate settings; None where the body doesn't expose them
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReviewSettings {
+    pub image_review: Option<ConfigSetting>,
+    pub auto_rotate: Option<ConfigSetting>,
+}
 
-            let metering_mode = Self::get_radio_value(&camera, &[
-                "meteringmode", "meteringmodedial", "metering",
-            ]);
+/// The body's exposure delay setting
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExposureDelay {
+    pub setting: ConfigSetting,
+    /// Current delay in seconds (0 when off); None if the value isn't a recognizable duration
+    pub seconds: Option<f32>,
+}
 
-            // Try to get battery level
-            let battery_level = camera.config_key::<gphoto2::widget::RangeWidget>("batterylevel")
-                .wait()
-                .ok()
-                .map(|w| w.value());
+/// Required params that didn't resolve through the alias lists, with likely config keys
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AliasReport {
+    pub model: String,
+    pub unresolved: Vec<String>,
+    /// Config keys whose label matches each unresolved param, as alias candidates
+    pub candidates: std::collections::HashMap<String, Vec<String>>,
+}
+
+/// An attached camera as listed by gphoto2, without opening it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeviceInfo {
+    pub model: String,
+    pub port: String,
+    /// Held by this app's current connection; other devices are free to connect to unless
+    /// another program has claimed them, which only shows when connecting
+    pub connected: bool,
+}
+
+/// libgphoto2 version and what it currently sees, for support triage
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackendInfo {
+    pub available: bool,
+    /// libgphoto2 version string; None if the library couldn't report it
+    pub version: Option<String>,
+    /// Why the backend couldn't be initialized or queried
+    pub error: Option<String>,
+    /// Cameras the drivers detected, with the model name the driver matched them to
+    pub cameras: Vec<DeviceInfo>,
+    /// Every model the installed drivers support, whether or not one is attached
+    pub supported_cameras: Vec<SupportedCamera>,
+}
+
+/// A camera model listed in libgphoto2's abilities list
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SupportedCamera {
+    pub model: String,
+    /// Driver (camlib) that handles the model, e.g. "ptp2"
+    pub driver: String,
+}
+
+/// A capture waiting in the temp area for confirm_capture or discard_capture
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PendingCapture {
+    pub pending_id: String,
+    pub preview: RemotePreview,
+}
+
+/// Where a pending capture is held and where it goes once confirmed
+struct PendingFile {
+    temp_path: PathBuf,
+    original_name: String,
+    target_folder: Option<String>,
+    camera_model: String,
+    camera_file_size: Option<u64>,
+}
+
+/// The body's time zone and DST settings; None where the body doesn't expose them
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimeZoneSettings {
+    pub timezone: Option<ConfigSetting>,
+    pub dst: Option<ConfigSetting>,
+    /// The body keeps UTC and derives local time from its zone, so the zone must be right for EXIF
+    pub utc_clock: bool,
+}
+
+/// What sync_camera_time wrote
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClockSync {
+    pub utc_clock: bool,
+    /// Zone choice set from the host, if requested and available
+    pub timezone: Option<String>,
+    pub dst: Option<bool>,
+}
+
+/// A raw PTP device property as the camera reports it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PtpValue {
+    pub code: u16,
+    pub label: String,
+    pub value: String,
+    /// Enumerated values the device accepts; empty for free-form and ranged properties
+    pub choices: Vec<String>,
+    pub readonly: bool,
+}
+
+/// A capture exposed to a target brightness, with the compensation it ended on
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AutoExposureResult {
+    pub capture: CaptureResult,
+    /// Exposure compensation choice left on the body, as the camera labels it
+    pub exposure_compensation: String,
+    pub ev: f32,
+    /// Preview brightness (0..1) measured at that compensation
+    pub preview_mean: f32,
+    pub iterations: u32,
+}
+
+/// Focus beep and AF-assist lamp settings; None where the body doesn't expose them
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QuietSettings {
+    pub beep: Option<ConfigSetting>,
+    pub af_assist: Option<ConfigSetting>,
+}
+
+/// Drive, AF and metering settings read together for the shooting-mode panel
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModeChoices {
+    pub drive_mode: Option<ConfigSetting>,
+    pub focus_mode: Option<ConfigSetting>,
+    pub metering_mode: Option<ConfigSetting>,
+}
+
+/// Attached lens details, as far as the body or the last capture's EXIF reports them
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LensInfo {
+    pub attached: bool,
+    pub name: Option<String>,
+    /// Widest aperture as an f-number (e.g. 2.8)
+    pub min_aperture: Option<f32>,
+    /// Narrowest aperture as an f-number (e.g. 22)
+    pub max_aperture: Option<f32>,
+    /// Focal range in mm; equal ends for a prime
+    pub min_focal_length: Option<f32>,
+    pub max_focal_length: Option<f32>,
+}
+
+/// Camera buffer fill state, reported by the body or inferred from busy captures
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BufferStatus {
+    /// Frames that still fit in the buffer, when the body reports it
+    pub frames_available: Option<u32>,
+    pub full: bool,
+    /// True when fullness was inferred from consecutive busy capture errors
+    pub inferred: bool,
+}
+
+/// A file or folder on the camera's card
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CardEntry {
+    pub name: String,
+    pub is_folder: bool,
+    /// Size on the card; None for folders
+    pub size: Option<u64>,
+}
+
+/// Node of the card's folder tree
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CardNode {
+    pub name: String,
+    /// Full camera path ("/store_00010001/DCIM/100CANON")
+    pub path: String,
+    pub is_folder: bool,
+    /// Size on the card; None for folders
+    pub size: Option<u64>,
+    /// Folder contents; None for files and for folders beyond max_depth, which the UI
+    /// expands on demand by requesting a tree rooted at their path
+    pub children: Option<Vec<CardNode>>,
+}
+
+/// Why a camera connection started or ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ConnectionReason {
+    /// Explicit connect/disconnect from the UI
+    User,
+    /// Picked up by the monitor's auto-connect
+    Auto,
+    /// Switched to another camera after the active one was lost
+    Failover,
+    /// USB/PTP error while talking to the camera
+    UsbError,
+    /// The camera stopped answering in time
+    Timeout,
+    /// libgphoto2 crashed inside an event wait
+    Panic,
+}
+
+impl ConnectionReason {
+    /// Classify a disconnect from the gphoto2 error that caused it
+    fn from_error(error: &str) -> Self {
+        let error = error.to_lowercase();
+        if error.contains("timeout") || error.contains("timed out") {
+            ConnectionReason::Timeout
+        } else {
+            ConnectionReason::UsbError
+        }
+    }
+}
+
+/// Entry in the connect/disconnect history
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectionEvent {
+    /// RFC 3339 local time
+    pub timestamp: String,
+    pub connected: bool,
+    pub reason: ConnectionReason,
+    pub model: Option<String>,
+    pub port: Option<String>,
+    /// Underlying error for unexpected disconnects
+    pub detail: Option<String>,
+}
+
+/// Overheat warning level reported by the body
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ThermalStatus {
+    Normal,
+    Warning,
+    /// The body is about to (or already did) stop shooting to cool down
+    Critical,
+}
+
+impl ThermalStatus {
+    /// Map a warning value ("Off", "0", "High temperature", "Shooting stopped", ...)
+    pub fn from_value(value: &str) -> Self {
+        let lower = value.trim().to_lowercase();
+        if lower.is_empty() || matches!(lower.as_str(), "off" | "none" | "normal" | "0") {
+            ThermalStatus::Normal
+        } else if lower.contains("critical") || lower.contains("stop") || lower.contains("shut") || lower == "2" {
+            ThermalStatus::Critical
+        } else {
+            ThermalStatus::Warning
+        }
+    }
+}
+
+/// Outcome of an interval/timelapse run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntervalSummary {
+    pub frames_captured: u32,
+    pub frames_failed: u32,
+    pub elapsed_secs: f64,
+    /// Folder the frames were downloaded to
+    pub folder: Option<String>,
+}
+
+/// Min/max/average of a set of timings in milliseconds
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimingStats {
+    pub min_ms: f64,
+    pub max_ms: f64,
+    pub avg_ms: f64,
+}
+
+impl TimingStats {
+    fn from_durations(durations: &[Duration]) -> Option<Self> {
+        if durations.is_empty() {
+            return None;
+        }
+        let ms: Vec<f64> = durations.iter().map(|d| d.as_secs_f64() * 1000.0).collect();
+        Some(TimingStats {
+            min_ms: ms.iter().copied().fold(f64::INFINITY, f64::min),
+            max_ms: ms.iter().copied().fold(0.0, f64::max),
+            avg_ms: ms.iter().sum::<f64>() / ms.len() as f64,
+        })
+    }
+}
+
+/// Result of a capture benchmark run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BenchmarkReport {
+    pub shots_requested: u32,
+    pub shots_completed: u32,
+    /// Shutter press until the camera reports the new file
+    pub capture: Option<TimingStats>,
+    /// Transfer of the file to the host
+    pub download: Option<TimingStats>,
+    pub total_bytes: u64,
+    /// Bytes downloaded over time spent downloading
+    pub throughput_mb_per_sec: f64,
+    pub elapsed_secs: f64,
+    /// Error of the first failed shot, if any
+    pub first_error: Option<String>,
+}
+
+/// Capture dimensions per camera model, evicting the least recently used model past capacity
+#[derive(Default)]
+struct DimensionCache {
+    entries: std::collections::HashMap<String, (u32, u32)>,
+    /// Models from least to most recently used
+    order: std::collections::VecDeque<String>,
+}
+
+impl DimensionCache {
+    fn touch(&mut self, model: &str) {
+        if let Some(index) = self.order.iter().position(|m| m == model) {
+            if let Some(entry) = self.order.remove(index) {
+                self.order.push_back(entry);
+            }
+        }
+    }
+
+    fn get(&mut self, model: &str) -> Option<(u32, u32)> {
+        let dimensions = self.entries.get(model).copied()?;
+        self.touch(model);
+        Some(dimensions)
+    }
+
+    fn insert(&mut self, model: String, dimensions: (u32, u32), capacity: usize) {
+        if self.entries.insert(model.clone(), dimensions).is_some() {
+            self.touch(&model);
+        } else {
+            self.order.push_back(model);
+        }
+        while self.entries.len() > capacity.max(1) {
+            let Some(oldest) = self.order.pop_front() else {
+                break;
+            };
+            self.entries.remove(&oldest);
+        }
+    }
+}
+
+/// Setting a bracket steps through, and how its frames are labelled
+#[derive(Clone, Copy)]
+struct BracketSetting {
+    keys: &'static [&'static str],
+    label: &'static str,
+    /// Prefix of the sequence id the frames share
+    sequence_prefix: &'static str,
+}
+
+/// Setting an exposure bracket varies and the choice for each frame
+struct BracketPlan {
+    keys: &'static [&'static str],
+    label: &'static str,
+    /// (EV offset, choice) per frame, after clamping and dropping duplicates
+    frames: Vec<(f32, String)>,
+    /// Some frames were clamped to the body's range or merged
+    clamped: bool,
+}
+
+/// Progress of the running interval/timelapse
+struct IntervalState {
+    started: Instant,
+    folder: Option<String>,
+    frames_captured: u32,
+    frames_failed: u32,
+    /// Wakes the interval loop out of its inter-frame wait when stopped
+    cancel: Arc<tokio::sync::Notify>,
+}
+
+impl IntervalState {
+    fn summary(&self) -> IntervalSummary {
+        IntervalSummary {
+            frames_captured: self.frames_captured,
+            frames_failed: self.frames_failed,
+            elapsed_secs: self.started.elapsed().as_secs_f64(),
+            folder: self.folder.clone(),
+        }
+    }
+}
+
+/// Per-capture options passed with a capture request
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct CaptureOptions {
+    /// Fire without re-focusing, keeping a lock taken by lock_focus (staged-release bodies only)
+    pub skip_af: bool,
+    /// Star rating (0-5) written into the editor's sidecar at capture time
+    pub rating: Option<u8>,
+    /// Color label flag, stored the same way the editor stores color labels
+    pub color_label: Option<String>,
+    /// Exact file name (without or with the camera's extension) instead of the naming template
+    pub output_name: Option<String>,
+    /// Store the frame under `calibration/<kind>/` and tag it as a calibration frame
+    pub calibration: Option<CalibrationKind>,
+    /// Ask the editor to load the frame once it is downloaded (`camera:open_in_editor`)
+    pub open_in_editor: bool,
+    /// Run autofocus first and fail instead of capturing if it doesn't lock
+    pub autofocus: bool,
+}
+
+/// User-configurable tethering behavior
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct TetherOptions {
+    /// Linux only: kill gvfs gphoto2 monitors that claim the camera before connecting
+    pub release_usb_on_connect: bool,
+    /// Switch to the next connected camera when the active one disappears
+    pub failover: bool,
+    /// Let auto-connect reopen a network (PTP/IP) camera at its last address after it drops
+    pub reconnect_network_camera: bool,
+    /// Write an .xmp sidecar with capture settings next to RAW captures
+    pub write_xmp_sidecar: bool,
+    /// Name files after the camera's frame counter (IMG_1234.CR3) instead of a host timestamp
+    pub name_from_camera_sequence: bool,
+    /// Prefix sequence-based names with the capture date to survive counter rollover
+    pub sequence_date_prefix: bool,
+    /// Download folder override for RAW files (e.g. a large archive disk)
+    pub raw_dir: Option<String>,
+    /// Download folder override for JPEG files (e.g. a fast SSD for review)
+    pub jpg_dir: Option<String>,
+    /// Per-camera subfolder of the download folder, e.g. "{serial}" or "{model}/{serial}";
+    /// `{serial}` falls back to the model on bodies that don't report one
+    pub camera_folder_template: Option<String>,
+    /// Emit a focus peaking overlay for live view frames on `camera:liveview_peaking`
+    pub focus_peaking: bool,
+    /// Check each download against the card's file size, re-download on mismatch, and checksum it
+    pub verify_downloads: bool,
+    /// Coalesce set_config_value calls per key within this window (ms); 0 applies every call
+    pub config_debounce_ms: u64,
+    /// Detect camera-button captures by listing the card when the body never sends NewFile events
+    pub filesystem_poll_fallback: bool,
+    /// How many camera filesystem operations may run at once; 1 keeps USB transactions serial
+    pub camera_io_concurrency: usize,
+    /// Camera-button files larger than this (bytes) stay on the card, e.g. long video clips
+    pub max_auto_download_size: Option<u64>,
+    /// Turn off image review when a session starts, so the body doesn't drop into playback
+    pub disable_review_on_session: bool,
+    /// Turn off the focus beep and AF-assist lamp when a session starts
+    pub quiet_on_session: bool,
+    /// Write a contact sheet into the session folder when a session ends
+    pub contact_sheet_on_session_end: bool,
+    /// Put the camera into power-save between timelapse frames that are far enough apart
+    pub sleep_between_frames: bool,
+    /// How many times a capture is retried after a retryable error
+    pub capture_retries: u32,
+    /// Error substrings (case-insensitive) that make a failed capture worth retrying
+    pub retryable_errors: Vec<String>,
+    /// How many recent `camera:captured` results are kept for get_recent_captures
+    pub recent_captures_size: usize,
+    /// Also append connect/disconnect events to this file as JSON lines
+    pub connection_log_path: Option<String>,
+    /// Camera models whose capture dimensions are cached; the least recently used is evicted
+    pub dimension_cache_size: usize,
+    /// Sound file played after each successful capture, through the OS's own player
+    pub play_sound_on_capture: Option<String>,
+    /// Play the system alert sound after each successful capture
+    pub beep_on_capture: bool,
+    /// POST each capture to this HTTP endpoint after download, as multipart with its metadata
+    pub upload_endpoint: Option<String>,
+    /// Presets applied automatically on connect, keyed by model or by serial number for one specific body
+    pub auto_apply_profiles: std::collections::HashMap<String, CameraPreset>,
+}
+
+impl Default for TetherOptions {
+    fn default() -> Self {
+        Self {
+            release_usb_on_connect: false,
+            failover: false,
+            reconnect_network_camera: true,
+            write_xmp_sidecar: false,
+            name_from_camera_sequence: false,
+            sequence_date_prefix: false,
+            raw_dir: None,
+            jpg_dir: None,
+            camera_folder_template: None,
+            focus_peaking: false,
+            verify_downloads: false,
+            config_debounce_ms: 0,
+            filesystem_poll_fallback: false,
+            camera_io_concurrency: 1,
+            max_auto_download_size: None,
+            disable_review_on_session: false,
+            quiet_on_session: false,
+            contact_sheet_on_session_end: false,
+            sleep_between_frames: false,
+            capture_retries: 1,
+            retryable_errors: vec!["i/o in progress".to_string()],
+            recent_captures_size: 50,
+            connection_log_path: None,
+            dimension_cache_size: 64,
+            play_sound_on_capture: None,
+            beep_on_capture: false,
+            upload_endpoint: None,
+            auto_apply_profiles: std::collections::HashMap::new(),
+        }
+    }
+}
+
+/// Marks an exclusive camera operation (capture, metering, bracket) as running until dropped;
+/// live view pauses while one is held and refuses to start
+struct OperationGuard {
+    slot: Arc<std::sync::Mutex<Option<&'static str>>>,
+}
+
+impl Drop for OperationGuard {
+    fn drop(&mut self) {
+        *self.slot.lock().unwrap() = None;
+    }
+}
+
+/// Global camera service state
+pub struct CameraService {
+    pub camera: Arc<Mutex<Option<Camera>>>,
+    capture_dir: PathBuf,
+    /// Current folder for downloading images from camera button presses
+    current_download_folder: Arc<Mutex<Option<String>>>,
+    /// Cached dimensions for faster capture (model -> (width, height))
+    cached_dimensions: Arc<Mutex<DimensionCache>>,
+    /// Last params read and when it was taken, to reduce USB chatter
+    cached_params: Arc<Mutex<Option<(Instant, CameraParams)>>>,
+    /// Whether autofocus is currently held by lock_focus
+    focus_locked: Arc<AtomicBool>,
+    /// Set while live view streams; the monitor leaves params polling to the live view loop
+    liveview_active: Arc<AtomicBool>,
+    /// Stop token of the running live view loop; set it to end that loop
+    liveview_stop: Arc<std::sync::Mutex<Option<Arc<AtomicBool>>>>,
+    /// Held by the live view loop while it grabs a frame; begin_operation waits on it
+    liveview_frame: Arc<Mutex<()>>,
+    /// Set by stop_all to make every background loop exit
+    shutdown: Arc<AtomicBool>,
+    /// Handles of spawned background loops so stop_all can await their teardown
+    background_tasks: Arc<std::sync::Mutex<Vec<tokio::task::JoinHandle<()>>>>,
+    /// Tethering options set from the frontend
+    options: Arc<Mutex<TetherOptions>>,
+    /// Set to stop auto-connect attempts until the next manual connect
+    auto_connect_cancelled: Arc<AtomicBool>,
+    /// Most recent capture path from either capture source
+    last_capture_path: Arc<Mutex<Option<String>>>,
+    /// Active session id; captures go into a subfolder named after it
+    active_session: Arc<Mutex<Option<String>>>,
+    /// Exclusive operation currently using the camera; captures and live view can't interleave
+    active_operation: Arc<std::sync::Mutex<Option<&'static str>>>,
+    /// Port of the connected camera; kept after an unexpected disconnect so failover can skip it
+    active_port: Arc<Mutex<Option<String>>>,
+    /// Set when the camera reports a property change; the monitor then does a full params refresh
+    params_dirty: Arc<AtomicBool>,
+    /// Capabilities of the connected camera, refreshed on every (re)connect
+    capabilities: Arc<Mutex<Option<CameraCapabilities>>>,
+    /// While set, the monitor doesn't poll the camera; only explicit commands touch it
+    settings_locked: Arc<AtomicBool>,
+    /// Set while the camera is put to sleep on purpose; the monitor must not mistake it for a disconnect
+    sleeping: Arc<AtomicBool>,
+    /// Consecutive captures that failed because the camera was busy writing its buffer
+    consecutive_busy: Arc<AtomicU32>,
+    /// Latest set_config_value generation per key, used to drop superseded debounced values
+    pending_config: Arc<std::sync::Mutex<std::collections::HashMap<String, u64>>>,
+    /// Camera files ("folder/name") already downloaded or present before the filesystem fallback started
+    known_camera_files: Arc<std::sync::Mutex<std::collections::HashSet<String>>>,
+    /// Sequence the event monitor's downloads are grouped into, with the frames received so far
+    active_sequence: Arc<Mutex<Option<(String, u32)>>>,
+    /// Running interval/timelapse, if any
+    interval_state: Arc<Mutex<Option<IntervalState>>>,
+    /// Set once this connection's first download has been measured and reported
+    transfer_stats_reported: Arc<AtomicBool>,
+    /// Acquired by every camera filesystem operation (capture and event downloads, card
+    /// browsing, thumbnails) and by the event monitor while it polls, so USB transactions don't
+    /// overlap and trip "I/O in progress" errors. Acquire it around the outermost operation only:
+    /// download_camera_file and the other helpers assume the caller holds it.
+    camera_io: Arc<tokio::sync::Semaphore>,
+    /// Captures stay on the card (CaptureDestination::CardOnly); the body's capture target alone
+    /// can't distinguish this from Both
+    card_only: Arc<AtomicBool>,
+    /// Current size of camera_io, from TetherOptions::camera_io_concurrency
+    camera_io_permits: Arc<AtomicUsize>,
+    /// Last thermal warning level seen by the monitor, to emit only on escalation
+    thermal_status: Arc<std::sync::Mutex<ThermalStatus>>,
+    /// Most recent `camera:captured` results, oldest first, for frontends that reload mid-session
+    recent_captures: Arc<std::sync::Mutex<std::collections::VecDeque<CaptureResult>>>,
+    /// Serial of the last connected body; kept across unexpected disconnects like active_port,
+    /// so the same camera coming back on another port is recognized as a reconnect
+    camera_serial: Arc<Mutex<Option<String>>>,
+    /// WebP/AVIF preview emitted after each capture of the current session, if enabled
+    remote_preview: Arc<Mutex<Option<RemotePreviewSettings>>>,
+    /// Connect/disconnect events, oldest first
+    connection_history: Arc<std::sync::Mutex<std::collections::VecDeque<ConnectionEvent>>>,
+    /// Feeds the upload worker; uploads run one at a time so a slow endpoint queues instead of stalling captures
+    upload_queue: Arc<std::sync::Mutex<Option<tokio::sync::mpsc::UnboundedSender<(String, CaptureResult)>>>>,
+    /// Captures shot with capture_pending, by pending id, until confirmed or discarded
+    pending_captures: Arc<std::sync::Mutex<std::collections::HashMap<String, PendingFile>>>,
+    /// Port picked in connect_camera; auto-connect reopens only this camera while it is set
+    preferred_port: Arc<Mutex<Option<String>>>,
+}
+
+impl CameraService {
+    /// Create a new camera service
+    pub fn new(capture_dir: PathBuf) -> Self {
+        Self {
+            camera: Arc::new(Mutex::new(None)),
+            capture_dir,
+            current_download_folder: Arc::new(Mutex::new(None)),
+            cached_dimensions: Arc::new(Mutex::new(DimensionCache::default())),
+            cached_params: Arc::new(Mutex::new(None)),
+            focus_locked: Arc::new(AtomicBool::new(false)),
+            liveview_active: Arc::new(AtomicBool::new(false)),
+            liveview_stop: Arc::new(std::sync::Mutex::new(None)),
+            liveview_frame: Arc::new(Mutex::new(())),
+            shutdown: Arc::new(AtomicBool::new(false)),
+            background_tasks: Arc::new(std::sync::Mutex::new(Vec::new())),
+            options: Arc::new(Mutex::new(TetherOptions::default())),
+            auto_connect_cancelled: Arc::new(AtomicBool::new(false)),
+            last_capture_path: Arc::new(Mutex::new(None)),
+            active_session: Arc::new(Mutex::new(None)),
+            active_operation: Arc::new(std::sync::Mutex::new(None)),
+            active_port: Arc::new(Mutex::new(None)),
+            params_dirty: Arc::new(AtomicBool::new(false)),
+            capabilities: Arc::new(Mutex::new(None)),
+            settings_locked: Arc::new(AtomicBool::new(false)),
+            sleeping: Arc::new(AtomicBool::new(false)),
+            consecutive_busy: Arc::new(AtomicU32::new(0)),
+            pending_config: Arc::new(std::sync::Mutex::new(std::collections::HashMap::new())),
+            known_camera_files: Arc::new(std::sync::Mutex::new(std::collections::HashSet::new())),
+            active_sequence: Arc::new(Mutex::new(None)),
+            interval_state: Arc::new(Mutex::new(None)),
+            transfer_stats_reported: Arc::new(AtomicBool::new(false)),
+            camera_io: Arc::new(tokio::sync::Semaphore::new(1)),
+            camera_io_permits: Arc::new(AtomicUsize::new(1)),
+            card_only: Arc::new(AtomicBool::new(false)),
+            thermal_status: Arc::new(std::sync::Mutex::new(ThermalStatus::Normal)),
+            recent_captures: Arc::new(std::sync::Mutex::new(std::collections::VecDeque::new())),
+            camera_serial: Arc::new(Mutex::new(None)),
+            remote_preview: Arc::new(Mutex::new(None)),
+            connection_history: Arc::new(std::sync::Mutex::new(std::collections::VecDeque::new())),
+            upload_queue: Arc::new(std::sync::Mutex::new(None)),
+            pending_captures: Arc::new(std::sync::Mutex::new(std::collections::HashMap::new())),
+            preferred_port: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// RAW extensions the backend classifies as RAW, from the central format list
+    pub fn supported_raw_extensions() -> Vec<String> {
+        let mut extensions: Vec<String> = Vec::new();
+        for (ext, _) in formats::RAW_EXTENSIONS {
+            if !extensions.iter().any(|e| e == ext) {
+                extensions.push(ext.to_string());
+            }
+        }
+        extensions
+    }
+
+    /// Extract real file extension from camera filename
+    /// Handles formats like "capt0000.jpg", "IMG_1234.CR3", "CRW_0001.JPG", etc.
+    fn extract_file_extension(original_name: &str) -> String {
+        // Convert to lowercase for easier matching
+        let name_lower = original_name.to_lowercase();
+
+        // Known RAW extensions, shared with the rest of the app
+        let raw_extensions = Self::supported_raw_extensions();
+
+        // Split by dots and process from right to left (last extension is the real one)
+        let parts: Vec<&str> = name_lower.rsplit('.').collect();
+
+        for (i, part) in parts.iter().enumerate() {
+            if part.is_empty() {
+                continue;
+            }
+
+            // Skip purely numeric parts or known camera internal prefixes
+            // capt0000, 0000, etc. are camera's internal naming, not real extensions
+            if part.chars().all(|c| c.is_numeric()) || part.starts_with("capt") {
+                continue;
+            }
+
+            // Check if it's a known extension
+            if *part == "jpg" || *part == "jpeg" || raw_extensions.iter().any(|ext| ext == part) {
+                return if *part == "jpeg" {
+                    "jpg".to_string()
+                } else {
+                    part.to_string()
+                };
+            }
+
+            // If we've gone past the first part (real extension) and hit something unknown,
+            // and the previous parts were all camera-specific, return jpg as default
+            if i > 0 {
+                return "jpg".to_string();
+            }
+        }
+
+        // Default to jpg if we can't determine
+        "jpg".to_string()
+    }
+
+    /// Extract the camera's frame number from its filename ("IMG_1234.CR3" -> "1234")
+    /// Captures held in camera RAM ("capt0000.jpg") have no card sequence
+    fn extract_sequence_number(original_name: &str) -> Option<String> {
+        let stem = original_name.split('.').next().unwrap_or(original_name);
+        if stem.to_lowercase().starts_with("capt") {
+            return None;
+        }
+        let digits: String = stem
+            .chars()
+            .rev()
+            .take_while(|c| c.is_ascii_digit())
+            .collect::<Vec<_>>()
+            .into_iter()
+            .rev()
+            .collect();
+        if digits.is_empty() { None } else { Some(digits) }
+    }
+
+    /// Build the local path for a downloaded capture, following the naming options
+    /// Appends _1, _2, ... when the name already exists so nothing is overwritten
+    fn capture_file_path(capture_dir: &std::path::Path, original_name: &str, ext: &str, options: &TetherOptions, output_name: Option<&str>) -> std::result::Result<PathBuf, String> {
+        let output_stem = output_name.and_then(|name| Self::output_name_stem(name, ext));
+        let sequence = if output_stem.is_none() && options.name_from_camera_sequence {
+            Self::extract_sequence_number(original_name)
+        } else {
+            None
+        };
+
+        let stem = match (output_stem, sequence) {
+            (Some(stem), _) => stem,
+            (None, Some(sequence)) if options.sequence_date_prefix => {
+                format!("{}_IMG_{}", chrono::Local::now().format("%Y%m%d"), sequence)
+            }
+            (None, Some(sequence)) => format!("IMG_{}", sequence),
+            (None, None) => {
+                let timestamp = SystemTime::now()
+                    .duration_since(SystemTime::UNIX_EPOCH)
+                    .map_err(|e| format!("Time error: {}", e))?
+                    .as_secs();
+                format!("capture_{:010}", timestamp)
+            }
+        };
+
+        let mut file_path = capture_dir.join(format!("{}.{}", stem, ext));
+        let mut suffix = 1;
+        while file_path.exists() {
+            file_path = capture_dir.join(format!("{}_{}.{}", stem, suffix, ext));
+            suffix += 1;
+        }
+        Ok(file_path)
+    }
+
+    /// Stem for a caller-chosen output name; the camera's extension is kept, so a matching one is dropped
+    /// Only the final path component is used, so a name can't write outside the capture folder.
+    fn output_name_stem(output_name: &str, ext: &str) -> Option<String> {
+        let name = std::path::Path::new(output_name.trim()).file_name()?.to_string_lossy().to_string();
+        let stem = match name.rsplit_once('.') {
+            Some((stem, name_ext)) if name_ext.eq_ignore_ascii_case(ext) => stem.to_string(),
+            _ => name,
+        };
+        if stem.is_empty() { None } else { Some(stem) }
+    }
+
+    /// Size of a local file, 0 if it can't be read
+    fn local_file_size(path: &str) -> u64 {
+        std::fs::metadata(path).map(|m| m.len()).unwrap_or(0)
+    }
+
+    /// Sizes of a RAW+JPEG pair as (raw, jpg) once both halves are on disk; None for single files
+    /// The other half is found by file stem next to this file and in its own per-type folder.
+    fn paired_file_sizes(file_path: &str, capture_dir: &std::path::Path, options: &TetherOptions, session_id: Option<&str>) -> (Option<u64>, Option<u64>) {
+        let is_jpg = |path: &str| {
+            let lower = path.to_lowercase();
+            lower.ends_with(".jpg") || lower.ends_with(".jpeg")
+        };
+        let path = std::path::Path::new(file_path);
+        let is_raw = Self::is_raw_file(file_path);
+        let Some(stem) = path.file_stem().filter(|_| is_raw || is_jpg(file_path)) else {
+            return (None, None);
+        };
+
+        // "dng" stands in for any RAW, as in list_session_captures
+        let mut dirs = vec![Self::dir_for_file_type(capture_dir, if is_raw { "jpg" } else { "dng" }, options, session_id)];
+        if let Some(parent) = path.parent().filter(|parent| !dirs.iter().any(|dir| dir == parent)) {
+            dirs.push(parent.to_path_buf());
+        }
+        let other = dirs
+            .iter()
+            .filter_map(|dir| std::fs::read_dir(dir).ok())
+            .flatten()
+            .filter_map(Result::ok)
+            .map(|entry| entry.path())
+            .find(|candidate| {
+                let candidate_str = candidate.to_string_lossy();
+                candidate.file_stem() == Some(stem)
+                    && candidate != path
+                    && if is_raw { is_jpg(&candidate_str) } else { Self::is_raw_file(&candidate_str) }
+            });
+        let Some(other) = other else {
+            return (None, None);
+        };
+
+        let own_size = Self::local_file_size(file_path);
+        let other_size = Self::local_file_size(&other.to_string_lossy());
+        if is_raw {
+            (Some(own_size), Some(other_size))
+        } else {
+            (Some(other_size), Some(own_size))
+        }
+    }
+
+    /// Size of a file on the camera's card as reported by the camera
+    fn camera_file_size(camera: &Camera, folder: &str, name: &str) -> Option<u64> {
+        camera.fs()
+            .file_info(folder, name)
+            .wait()
+            .ok()
+            .and_then(|info| info.file())
+            .and_then(|file| file.size())
+    }
+
+    /// Download a file from the camera, returning its size on the card
+    /// With `verify`, a local file whose size differs from the card's is downloaded again.
+    /// Downloads go to `<file>.part` and are renamed into place once complete, so a crash never
+    /// leaves a truncated file under the final name.
+    fn download_verified(camera: &Camera, folder: &str, name: &str, file_path: &std::path::Path, verify: bool) -> std::result::Result<Option<u64>, String> {
+        let part_path = Self::with_suffix(file_path, ".part");
+        let marker_path = Self::with_suffix(file_path, PART_SOURCE_SUFFIX);
+        std::fs::write(&marker_path, format!("{}\n{}", folder, name))
+            .map_err(|e| format!("Failed to write download marker: {}", e))?;
+
+        let fs = camera.fs();
+        let mut attempt = 1;
+        let camera_size = loop {
+            fs.download_to(folder, name, &part_path)
+                .wait()
+                .map_err(|e| format!("Download failed: {}", e))?;
+            let camera_size = Self::camera_file_size(camera, folder, name);
+            if !verify {
+                break camera_size;
+            }
+
+            let local_size = Self::local_file_size(&part_path.to_string_lossy());
+            match camera_size {
+                Some(expected) if expected != local_size => {
+                    eprintln!("{} [Camera] Size mismatch for {} (card {} bytes, local {} bytes), attempt {}/{}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), name, expected, local_size, attempt, DOWNLOAD_VERIFY_ATTEMPTS);
+                    if attempt >= DOWNLOAD_VERIFY_ATTEMPTS {
+                        return Err(format!("Downloaded file size mismatch for {}: card {} bytes, local {} bytes", name, expected, local_size));
+                    }
+                    attempt += 1;
+                }
+                _ => break camera_size,
+            }
+        };
+
+        std::fs::rename(&part_path, file_path)
+            .map_err(|e| format!("Failed to move download into place: {}", e))?;
+        let _ = std::fs::remove_file(&marker_path);
+        Ok(camera_size)
+    }
+
+    /// Append a suffix to a full file name ("a.CR3" -> "a.CR3.part")
+    fn with_suffix(path: &std::path::Path, suffix: &str) -> PathBuf {
+        let mut name = path.as_os_str().to_os_string();
+        name.push(suffix);
+        PathBuf::from(name)
+    }
+
+    /// Re-download frames whose download was interrupted, if they are still on the card
+    /// Looks in the given folders and their direct subfolders (sessions); returns the recovered paths.
+    fn recover_partial_downloads(camera: &Camera, dirs: &[PathBuf]) -> Vec<String> {
+        let mut markers = Vec::new();
+        for dir in dirs {
+            let Ok(entries) = std::fs::read_dir(dir) else {
+                continue;
+            };
+            for path in entries.filter_map(Result::ok).map(|entry| entry.path()) {
+                if path.is_dir() {
+                    if let Ok(sub_entries) = std::fs::read_dir(&path) {
+                        markers.extend(sub_entries.filter_map(Result::ok).map(|entry| entry.path()));
+                    }
+                } else {
+                    markers.push(path);
+                }
+            }
+        }
+
+        let mut recovered = Vec::new();
+        for marker_path in markers {
+            let marker = marker_path.to_string_lossy().to_string();
+            let Some(target) = marker.strip_suffix(PART_SOURCE_SUFFIX) else {
+                continue;
+            };
+            let target = PathBuf::from(target);
+            let Some((folder, name)) = std::fs::read_to_string(&marker_path)
+                .ok()
+                .and_then(|source| source.split_once('\n').map(|(f, n)| (f.to_string(), n.trim().to_string())))
+            else {
+                continue;
+            };
+
+            if Self::camera_file_size(camera, &folder, &name).is_none() {
+                eprintln!("{} [Camera] Interrupted download of {}/{} is no longer on the card", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), folder, name);
+                let _ = std::fs::remove_file(Self::with_suffix(&target, ".part"));
+                let _ = std::fs::remove_file(&marker_path);
+                continue;
+            }
+
+            match Self::download_verified(camera, &folder, &name, &target, true) {
+                Ok(_) => {
+                    eprintln!("{} [Camera] Recovered interrupted download: {}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), target.display());
+                    recovered.push(target.to_string_lossy().to_string());
+                }
+                Err(e) => {
+                    eprintln!("{} [Camera] Failed to recover {}: {}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), target.display(), e);
+                }
+            }
+        }
+        recovered
+    }
+
+    /// Fire the shutter, retrying on the configured transient errors
+    fn fire_shutter(camera: &Camera, skip_af: bool, options: &TetherOptions) -> std::result::Result<CameraFilePath, String> {
+        // Retries go through the same release, so a held focus isn't lost to a refocusing capture
+        let held_focus = skip_af && Self::has_remote_release(camera);
+        let shoot = || {
+            if held_focus {
+                Self::capture_with_held_focus(camera)
+            } else {
+                camera.capture_image().wait().map_err(|e| e.to_string())
+            }
+        };
+        let mut result = shoot();
+
+        // Only retry on the configured transient errors
+        let mut retries = 0;
+        while let Err(e) = &result {
+            if retries >= options.capture_retries || !Self::is_retryable_error(e, options) {
+                break;
+            }
+            retries += 1;
+            eprintln!("{} [Camera] Capture failed ({}), retry {}/{}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), e, retries, options.capture_retries);
+            std::thread::sleep(std::time::Duration::from_secs(1));
+            result = shoot();
+        }
+
+        match result {
+            Ok(path) => Ok(path),
+            Err(e) if retries > 0 => Err(format!("Capture failed after {} retries: {}", retries, e)),
+            Err(e) => Err(format!("Capture failed: {}", e)),
+        }
+    }
+
+    /// Check a capture error against the configured retryable substrings
+    fn is_retryable_error(error: &str, options: &TetherOptions) -> bool {
+        let error = error.to_lowercase();
+        options
+            .retryable_errors
+            .iter()
+            .any(|pattern| !pattern.is_empty() && error.contains(&pattern.to_lowercase()))
+    }
+
+    /// BLAKE3 hash of a downloaded file as hex
+    fn file_checksum(path: &str) -> Option<String> {
+        let mut file = std::fs::File::open(path).ok()?;
+        let mut hasher = blake3::Hasher::new();
+        std::io::copy(&mut file, &mut hasher).ok()?;
+        Some(hasher.finalize().to_hex().to_string())
+    }
+
+    /// Pick the download folder for a file type, honoring the RAW/JPEG overrides
+    /// Overrides still get the session subfolder so sessions stay separated on every volume
+    fn dir_for_file_type(capture_dir: &std::path::Path, ext: &str, options: &TetherOptions, session_id: Option<&str>) -> PathBuf {
+        let is_raw = Self::is_raw_file(&format!("file.{}", ext));
+        let override_dir = if is_raw {
+            options.raw_dir.as_ref()
+        } else if ext == "jpg" {
+            options.jpg_dir.as_ref()
+        } else {
+            None
+        };
+
+        match (override_dir, session_id) {
+            (Some(dir), Some(session)) => PathBuf::from(dir).join(session),
+            (Some(dir), None) => PathBuf::from(dir),
+            (None, _) => capture_dir.to_path_buf(),
+        }
+    }
+
+    /// Check if a file path is a RAW file
+    fn is_raw_file(path: &str) -> bool {
+        formats::is_raw_file(path)
+    }
+
+    /// Get image dimensions, supporting both regular formats and RAW files
+    fn get_image_dimensions(file_path: &PathBuf) -> Option<(u32, u32)> {
+        // First try with image crate (for JPEG, PNG, etc.)
+        if let Ok(dim) = image_crate::image_dimensions(file_path) {
+            return Some(dim);
+        }
+
+        // If that fails and it's a RAW file, try with rawler
+        if Self::is_raw_file(&file_path.to_string_lossy()) {
+            if let Ok(data) = std::fs::read(file_path) {
+                let source = RawSource::new_from_slice(&data);
+                if let Ok(decoder) = rawler::get_decoder(&source) {
+                    if let Ok(raw_image) = decoder.raw_image(&source, &RawDecodeParams::default(), false) {
+                        let w = raw_image.width as u32;
+                        let h = raw_image.height as u32;
+                        return Some((w, h));
+                    }
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Helper to get a RadioWidget value with multiple key attempts
+    fn get_radio_value(camera: &Camera, keys: &[&str]) -> Option<String> {
+        for key in keys {
+            if let Ok(widget) = camera.config_key::<gphoto2::widget::RadioWidget>(key).wait() {
+                return Some(widget.choice().to_string());
+            }
+        }
+        None
+    }
+
+    /// Whether the shooting mode widget is readonly, i.e. set by the mode dial on the body
+    fn is_shooting_mode_locked(camera: &Camera) -> bool {
+        SHOOTING_MODE_KEYS
+            .iter()
+            .find_map(|key| camera.config_key::<gphoto2::widget::RadioWidget>(key).wait().ok())
+            .map_or(false, |widget| widget.readonly())
+    }
+
+    /// Helper to set a RadioWidget choice and apply it to the camera
+    fn set_radio_value(camera: &Camera, key: &str, value: &str) -> std::result::Result<(), String> {
+        let widget = camera.config_key::<gphoto2::widget::RadioWidget>(key)
+            .wait()
+            .map_err(|e| format!("Failed to get config '{}': {}", key, e))?;
+
+        // Check if readonly
+        if widget.readonly() {
+            return Err(format!("Config '{}' is readonly", key));
+        }
+
+        widget.set_choice(value)
+            .map_err(|e| format!("Failed to set choice '{}' for '{}': {}", value, key, e))?;
+
+        camera.set_config(&widget)
+            .wait()
+            .map_err(|e| format!("Failed to apply config '{}': {}", key, e))
+    }
+
+    /// Get a clone of the connected camera handle
+    async fn connected_camera(&self) -> std::result::Result<Camera, String> {
+        let camera_guard = self.camera.lock().await;
+        camera_guard
+            .as_ref()
+            .cloned()
+            .ok_or_else(|| "No camera connected".to_string())
+    }
+
+    /// Claim the camera for an exclusive operation
+    /// Fails with a clear busy error when another operation runs. A streaming live view is
+    /// paused instead: the frame in flight finishes, then frames are skipped until the guard drops
+    async fn begin_operation(&self, name: &'static str) -> std::result::Result<OperationGuard, String> {
+        let guard = {
+            let mut current = self.active_operation.lock().unwrap();
+            if let Some(running) = *current {
+                return Err(TetherError::Busy(running.to_string()).into());
+            }
+            *current = Some(name);
+            OperationGuard { slot: self.active_operation.clone() }
+        };
+        drop(self.liveview_frame.lock().await);
+        Ok(guard)
+    }
+
+    /// Check whether the body supports Canon-style staged shutter release
+    fn has_remote_release(camera: &Camera) -> bool {
+        camera.config_key::<gphoto2::widget::RadioWidget>("eosremoterelease").wait().is_ok()
+    }
+
+    /// Fire the shutter without re-focusing while a half-press is held
+    /// Waits for the resulting NewFile event since the release sequence doesn't return a path
+    fn capture_with_held_focus(camera: &Camera) -> std::result::Result<CameraFilePath, String> {
+        Self::set_radio_value(camera, "eosremoterelease", "Press Full")?;
+        Self::set_radio_value(camera, "eosremoterelease", "Release Full")?;
+
+        let deadline = Instant::now() + Duration::from_secs(30);
+        while Instant::now() < deadline {
+            match camera.wait_event(Duration::from_millis(500)).wait() {
+                Ok(CameraEvent::NewFile(path)) => return Ok(path),
+                Ok(_) => {}
+                Err(e) => return Err(format!("Failed waiting for captured file: {}", e)),
+            }
+        }
+
+        Err("Timed out waiting for captured file".to_string())
+    }
+
+    /// Autofocus and hold the result until unlock_focus
+    /// Canon bodies hold a half-press via eosremoterelease; others run a one-shot AF drive
+    pub async fn lock_focus(&self) -> std::result::Result<(), String> {
+        let camera = self.connected_camera().await?;
+
+        tokio::task::spawn_blocking(move || {
+            if Self::has_remote_release(&camera) {
+                Self::set_radio_value(&camera, "eosremoterelease", "Press Half")
+            } else {
+                let widget = camera.config_key::<gphoto2::widget::ToggleWidget>("autofocusdrive")
+                    .wait()
+                    .map_err(|_| "Camera does not support remote autofocus".to_string())?;
+                widget.set_toggled(true);
+                camera.set_config(&widget)
+                    .wait()
+                    .map_err(|e| format!("Failed to trigger autofocus: {}", e))
+            }
+        })
+        .await
+        .map_err(|e| format!("Task join error: {}", e))??;
+
+        self.focus_locked.store(true, Ordering::Relaxed);
+        eprintln!("{} [Camera] Focus locked", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"));
+        Ok(())
+    }
+
+    /// Run autofocus once and wait for the body to confirm it
+    pub async fn autofocus(&self) -> std::result::Result<(), String> {
+        let camera = self.connected_camera().await?;
+        tokio::task::spawn_blocking(move || Self::autofocus_blocking(&camera, AUTOFOCUS_TIMEOUT))
+            .await
+            .map_err(|e| format!("Task join error: {}", e))??;
+        eprintln!("{} [Camera] Autofocus locked", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"));
+        Ok(())
+    }
+
+    /// Half-press (Canon) or drive AF, then poll focus confirmation until `timeout`
+    /// Only a confirmed lock succeeds; bodies that report no confirmation at all get an error,
+    /// since a half-press is accepted whether or not focus was found.
+    fn autofocus_blocking(camera: &Camera, timeout: Duration) -> std::result::Result<(), String> {
+        let remote_release = Self::has_remote_release(camera);
+        if remote_release {
+            Self::set_radio_value(camera, "eosremoterelease", "Press Half")?;
+        } else {
+            let widget = camera.config_key::<gphoto2::widget::ToggleWidget>("autofocusdrive")
+                .wait()
+                .map_err(|_| "Camera does not support remote autofocus".to_string())?;
+            widget.set_toggled(true);
+            camera.set_config(&widget)
+                .wait()
+                .map_err(|e| format!("Failed to trigger autofocus: {}", e))?;
+        }
+
+        let deadline = Instant::now() + timeout;
+        let mut reported = false;
+        let result = loop {
+            match Self::read_focus_confirmed(camera) {
+                Some(true) => break Ok(()),
+                Some(false) => reported = true,
+                None => {}
+            }
+            if Instant::now() >= deadline {
+                break Err(if reported {
+                    format!("Autofocus did not lock within {} seconds", timeout.as_secs())
+                } else {
+                    "Camera does not report focus confirmation, cannot confirm autofocus".to_string()
+                });
+            }
+            std::thread::sleep(Duration::from_millis(100));
+        };
+
+        if remote_release {
+            Self::set_radio_value(camera, "eosremoterelease", "Release Half")?;
+        }
+        result
+    }
+
+    /// Release a focus lock taken by lock_focus
+    pub async fn unlock_focus(&self) -> std::result::Result<(), String> {
+        let camera = self.connected_camera().await?;
+
+        tokio::task::spawn_blocking(move || {
+            if Self::has_remote_release(&camera) {
+                Self::set_radio_value(&camera, "eosremoterelease", "Release Half")
+            } else {
+                // One-shot AF drive has nothing to release
+                Ok(())
+            }
+        })
+        .await
+        .map_err(|e| format!("Task join error: {}", e))??;
+
+        self.focus_locked.store(false, Ordering::Relaxed);
+        eprintln!("{} [Camera] Focus unlocked", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"));
+        Ok(())
+    }
+
+    /// Wait for a camera I/O slot; see `camera_io`
+    async fn camera_io_permit(&self) -> Option<tokio::sync::SemaphorePermit<'_>> {
+        self.camera_io.acquire().await.ok()
+    }
+
+    /// Replace the tethering options, resizing the camera I/O limit to match
+    pub async fn set_options(&self, options: TetherOptions) {
+        let permits = options.camera_io_concurrency.max(1);
+        let current = self.camera_io_permits.swap(permits, Ordering::Relaxed);
+        if permits > current {
+            self.camera_io.add_permits(permits - current);
+        } else if permits < current {
+            // Retire the extra permits as running operations hand them back
+            let semaphore = self.camera_io.clone();
+            tokio::spawn(async move {
+                if let Ok(extra) = semaphore.acquire_many_owned((current - permits) as u32).await {
+                    extra.forget();
+                }
+            });
+        }
+        *self.options.lock().await = options;
+    }
+
+    /// Create a gphoto2 context, reporting a missing or broken libgphoto2 as BackendUnavailable
+    fn new_context() -> std::result::Result<Context, String> {
+        Context::new().map_err(|e| TetherError::BackendUnavailable(e.to_string()).into())
+    }
+
+    /// Check whether the gphoto2 backend can be initialized, so the UI can hide tethering
+    pub async fn backend_available() -> bool {
+        tokio::task::spawn_blocking(|| Self::new_context().is_ok())
+            .await
+            .unwrap_or(false)
+    }
+
+    /// Load libgphoto2's abilities list: every model its installed drivers support
+    fn supported_cameras() -> std::result::Result<Vec<SupportedCamera>, String> {
+        use libgphoto2_sys as sys;
+        // The gphoto2 crate keeps its abilities list private, so read it through the C API
+        unsafe {
+            let mut list: *mut sys::CameraAbilitiesList = std::ptr::null_mut();
+            if sys::gp_abilities_list_new(&mut list) < 0 {
+                return Err("Failed to create the camera abilities list".to_string());
+            }
+            let loaded = sys::gp_abilities_list_load(list, std::ptr::null_mut());
+            let mut cameras = Vec::new();
+            if loaded >= 0 {
+                for index in 0..sys::gp_abilities_list_count(list).max(0) {
+                    let mut abilities: sys::CameraAbilities = std::mem::zeroed();
+                    if sys::gp_abilities_list_get_abilities(list, index, &mut abilities) < 0 {
+                        continue;
+                    }
+                    cameras.push(SupportedCamera {
+                        model: std::ffi::CStr::from_ptr(abilities.model.as_ptr()).to_string_lossy().into_owned(),
+                        driver: std::ffi::CStr::from_ptr(abilities.id.as_ptr()).to_string_lossy().into_owned(),
+                    });
+                }
+            }
+            sys::gp_abilities_list_free(list);
+            if loaded < 0 {
+                return Err(format!("Failed to load camera drivers (gphoto2 error {})", loaded));
+            }
+            Ok(cameras)
+        }
+    }
+
+    /// Report the libgphoto2 version, the models its drivers support and the cameras they detect
+    /// Never fails: initialization errors are returned in `error`, so the UI can show them.
+    pub async fn get_backend_info(&self) -> BackendInfo {
+        let version = gphoto2::library_version().ok().map(|version| version.to_string());
+        let initialized = tokio::task::spawn_blocking(|| Self::new_context().map(|_| ()))
+            .await
+            .map_err(|e| format!("Task join error: {}", e))
+            .and_then(|result| result);
+        if let Err(error) = initialized {
+            eprintln!("{} [Camera] Backend unavailable (libgphoto2 {}): {}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), version.as_deref().unwrap_or("unknown"), error);
+            return BackendInfo { available: false, version, error: Some(error), cameras: Vec::new(), supported_cameras: Vec::new() };
+        }
+
+        let supported = tokio::task::spawn_blocking(Self::supported_cameras)
+            .await
+            .map_err(|e| format!("Task join error: {}", e))
+            .and_then(|result| result);
+        let detected = self.enumerate_devices().await;
+        let error = supported.as_ref().err().or_else(|| detected.as_ref().err()).cloned();
+        BackendInfo {
+            available: true,
+            version,
+            error,
+            cameras: detected.unwrap_or_default(),
+            supported_cameras: supported.unwrap_or_default(),
+        }
+    }
+
+    /// Kill the gvfs gphoto2 processes that grab cameras on Linux desktops
+    /// Returns true if any process was signaled
+    #[cfg(target_os = "linux")]
+    fn release_usb_claims() -> bool {
+        let mut released = false;
+        for process in ["gvfsd-gphoto2", "gvfs-gphoto2-volume-monitor"] {
+            let killed = std::process::Command::new("pkill")
+                .args(["-f", process])
+                .status()
+                .map(|status| status.success())
+                .unwrap_or(false);
+            if killed {
+                eprintln!("{} [Camera] Released USB claim held by {}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), process);
+                released = true;
+            }
+        }
+        if released {
+            // Give the kernel a moment to drop the interface claim
+            std::thread::sleep(Duration::from_millis(500));
+        }
+        released
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn release_usb_claims() -> bool {
+        false
+    }
+
+    /// Play a sound file without waiting for it; a missing player only gets logged
+    fn play_capture_sound(path: &str) {
+        #[cfg(target_os = "macos")]
+        let command = {
+            let mut command = std::process::Command::new("afplay");
+            command.arg(path);
+            command
+        };
+        #[cfg(target_os = "windows")]
+        let command = {
+            let mut command = std::process::Command::new("powershell");
+            command.args(["-NoProfile", "-Command", &format!("(New-Object Media.SoundPlayer '{}').PlaySync()", path.replace('\'', "''"))]);
+            command
+        };
+        #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+        let command = {
+            let mut command = std::process::Command::new("paplay");
+            command.arg(path);
+            command
+        };
+        Self::spawn_sound_player(command);
+    }
+
+    /// Play the OS's own alert sound, for a beep with no sound file configured
+    /// A GUI app has no terminal to ring a bell on, so this goes through the sound player too.
+    fn play_system_beep() {
+        #[cfg(target_os = "macos")]
+        let command = {
+            let mut command = std::process::Command::new("afplay");
+            command.arg("/System/Library/Sounds/Tink.aiff");
+            command
+        };
+        #[cfg(target_os = "windows")]
+        let command = {
+            let mut command = std::process::Command::new("powershell");
+            // Play() returns immediately; keep the shell alive until the sound is out
+            command.args(["-NoProfile", "-Command", "[System.Media.SystemSounds]::Beep.Play(); Start-Sleep -Milliseconds 500"]);
+            command
+        };
+        #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+        let command = {
+            let mut command = std::process::Command::new("paplay");
+            command.arg("/usr/share/sounds/freedesktop/stereo/complete.oga");
+            command
+        };
+        Self::spawn_sound_player(command);
+    }
+
+    fn spawn_sound_player(mut command: std::process::Command) {
+        match command
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::null())
+            .spawn()
+        {
+            // Reap the player once it finishes so it doesn't linger as a zombie
+            Ok(mut child) => {
+                std::thread::spawn(move || child.wait());
+            }
+            Err(e) => {
+                eprintln!("{} [Camera] Failed to play capture sound: {}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), e);
+            }
+        }
+    }
+
+    /// Look up the negotiated USB speed of a "usb:BUS,DEV" port in sysfs
+    #[cfg(target_os = "linux")]
+    fn usb_connection_speed(port: &str) -> Option<String> {
+        let (bus, dev) = port.strip_prefix("usb:")?.split_once(',')?;
+        let (bus, dev) = (bus.trim().parse::<u32>().ok()?, dev.trim().parse::<u32>().ok()?);
+        let read_number = |path: &std::path::Path, name: &str| {
+            std::fs::read_to_string(path.join(name))
+                .ok()
+                .and_then(|value| value.trim().parse::<u32>().ok())
+        };
+
+        for entry in std::fs::read_dir("/sys/bus/usb/devices").ok()?.flatten() {
+            let path = entry.path();
+            if read_number(&path, "busnum") == Some(bus) && read_number(&path, "devnum") == Some(dev) {
+                let speed = std::fs::read_to_string(path.join("speed")).ok()?;
+                let speed = speed.trim();
+                let generation = match speed {
+                    "1.5" | "12" => "USB 1.1",
+                    "480" => "USB 2.0",
+                    "5000" => "USB 3.0",
+                    "10000" => "USB 3.1",
+                    "20000" => "USB 3.2",
+                    _ => "USB",
+                };
+                return Some(format!("{} ({} Mbps)", generation, speed));
+            }
+        }
+        None
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn usb_connection_speed(_port: &str) -> Option<String> {
+        None
+    }
+
+    /// Check whether a gphoto2 error means another process holds the USB interface
+    fn is_usb_claim_error(error_msg: &str) -> bool {
+        let error_msg = error_msg.to_lowercase();
+        error_msg.contains("could not claim") || error_msg.contains("usb")
+    }
+
+    /// Check whether a shooting mode string means full manual exposure
+    fn is_manual_mode(mode: &str) -> bool {
+        ExposureProgram::from_mode(mode) == Some(ExposureProgram::Manual)
+    }
+
+    /// Parse a number out of a config value like "f/2.8", "F4", "24mm" or "50"
+    fn parse_config_number(value: &str) -> Option<f32> {
+        let number: String = value
+            .trim()
+            .trim_start_matches(|c: char| !c.is_ascii_digit())
+            .chars()
+            .take_while(|c| c.is_ascii_digit() || *c == '.')
+            .collect();
+        number.parse::<f32>().ok()
+    }
+
+    /// Read a config value as text, whichever widget type the body uses
+    fn read_config_text(camera: &Camera, keys: &[&str]) -> Option<String> {
+        for key in keys {
+            if let Ok(widget) = camera.config_key::<gphoto2::widget::TextWidget>(key).wait() {
+                return Some(widget.value().to_string());
+            }
+            if let Ok(widget) = camera.config_key::<gphoto2::widget::RadioWidget>(key).wait() {
+                return Some(widget.choice().to_string());
+            }
+            if let Ok(widget) = camera.config_key::<gphoto2::widget::RangeWidget>(key).wait() {
+                return Some(widget.value().to_string());
+            }
+        }
+        None
+    }
+
+    /// Read lens details from the camera's config tree
+    /// Aperture limits come from the aperture choices, which are empty or non-numeric without a lens.
+    fn read_lens_info(camera: &Camera) -> Option<LensInfo> {
+        let name = Self::read_config_text(camera, LENS_NAME_KEYS)
+            .map(|name| name.trim().to_string());
+        let apertures: Vec<f32> = APERTURE_KEYS
+            .iter()
+            .find_map(|key| camera.config_key::<gphoto2::widget::RadioWidget>(key).wait().ok())
+            .map(|widget| {
+                widget.choices_iter()
+                    .filter_map(|choice| Self::parse_config_number(&choice))
+                    .filter(|f_number| *f_number > 0.0)
+                    .collect()
+            })
+            .unwrap_or_default();
+        let min_focal_length = Self::read_config_text(camera, MIN_FOCAL_LENGTH_KEYS)
+            .and_then(|value| Self::parse_config_number(&value))
+            .filter(|focal| *focal > 0.0);
+        let max_focal_length = Self::read_config_text(camera, MAX_FOCAL_LENGTH_KEYS)
+            .and_then(|value| Self::parse_config_number(&value))
+            .filter(|focal| *focal > 0.0);
+
+        if name.is_none() && apertures.is_empty() && min_focal_length.is_none() && max_focal_length.is_none() {
+            return None;
+        }
+
+        // Canon reports "None" as the lens name when the mount is empty
+        let attached = match name.as_deref() {
+            Some(name) if !name.is_empty() => !matches!(name.to_lowercase().as_str(), "none" | "unknown" | "no lens"),
+            _ => !apertures.is_empty() || min_focal_length.is_some(),
+        };
+        if !attached {
+            return Some(LensInfo::default());
+        }
+
+        Some(LensInfo {
+            attached,
+            name: name.filter(|name| !name.is_empty()),
+            min_aperture: apertures.iter().copied().reduce(f32::min),
+            max_aperture: apertures.iter().copied().reduce(f32::max),
+            min_focal_length,
+            max_focal_length,
+        })
+    }
+
+    /// Fill lens details the body didn't report from a capture's EXIF (LensModel, LensSpecification)
+    fn fill_lens_info_from_exif(info: &mut LensInfo, file_path: &str) {
+        let Ok(file) = std::fs::File::open(file_path) else {
+            return;
+        };
+        let Ok(exif) = exif::Reader::new().read_from_container(&mut std::io::BufReader::new(file)) else {
+            return;
+        };
+
+        if info.name.is_none() {
+            if let Some(field) = exif.get_field(exif::Tag::LensModel, exif::In::PRIMARY) {
+                if let exif::Value::Ascii(vec) = &field.value {
+                    info.name = vec.first()
+                        .map(|v| String::from_utf8_lossy(v).trim_matches(char::from(0)).trim().to_string())
+                        .filter(|name| !name.is_empty());
+                }
+            }
+        }
+
+        // LensSpecification: min focal, max focal, widest f-number at min focal, at max focal
+        if let Some(field) = exif.get_field(exif::Tag::LensSpecification, exif::In::PRIMARY) {
+            if let exif::Value::Rational(v) = &field.value {
+                let value = |i: usize| v.get(i).map(|r| r.to_f64() as f32).filter(|x| x.is_finite() && *x > 0.0);
+                info.min_focal_length = info.min_focal_length.or_else(|| value(0));
+                info.max_focal_length = info.max_focal_length.or_else(|| value(1));
+                info.min_aperture = info.min_aperture.or_else(|| value(2));
+            }
+        }
+    }
+
+    /// Get the attached lens and its aperture/focal range
+    /// Reports `attached: false` on an empty mount; None when neither the body nor EXIF says anything.
+    pub async fn get_lens_info(&self) -> std::result::Result<Option<LensInfo>, String> {
+        let camera = self.connected_camera().await?;
+        let last_capture = self.last_capture_path.lock().await.clone();
+
+        tokio::task::spawn_blocking(move || {
+            let mut info = Self::read_lens_info(&camera);
+            if let Some(file_path) = last_capture {
+                let needs_exif = info.as_ref().map_or(true, |info| {
+                    info.attached && (info.name.is_none() || info.min_focal_length.is_none())
+                });
+                if needs_exif {
+                    let mut filled = info.clone().unwrap_or(LensInfo { attached: true, ..Default::default() });
+                    Self::fill_lens_info_from_exif(&mut filled, &file_path);
+                    if info.is_some() || filled.name.is_some() || filled.min_focal_length.is_some() {
+                        info = Some(filled);
+                    }
+                }
+            }
+            info
+        })
+        .await
+        .map_err(|e| format!("Task join error: {}", e))
+    }
+
+    /// Read the first matching setting as a radio or an On/Off toggle
+    fn read_config_setting(camera: &Camera, keys: &[&str]) -> Option<ConfigSetting> {
+        for key in keys {
+            if let Ok(widget) = camera.config_key::<gphoto2::widget::RadioWidget>(key).wait() {
+                return Some(ConfigSetting {
+                    key: key.to_string(),
+                    value: widget.choice().to_string(),
+                    choices: widget.choices_iter().map(|c| c.to_string()).collect(),
+                });
+            }
+            if let Ok(widget) = camera.config_key::<gphoto2::widget::ToggleWidget>(key).wait() {
+                let value = if widget.toggled() == Some(true) { "On" } else { "Off" };
+                return Some(ConfigSetting {
+                    key: key.to_string(),
+                    value: value.to_string(),
+                    choices: vec!["Off".to_string(), "On".to_string()],
+                });
+            }
+        }
+        None
+    }
+
+    /// Write a setting read by read_config_setting
+    fn write_config_setting(camera: &Camera, key: &str, value: &str) -> std::result::Result<(), String> {
+        if let Ok(widget) = camera.config_key::<gphoto2::widget::ToggleWidget>(key).wait() {
+            widget.set_toggled(value.eq_ignore_ascii_case("on"));
+            return camera.set_config(&widget)
+                .wait()
+                .map_err(|e| format!("Failed to apply config '{}': {}", key, e));
+        }
+        Self::set_radio_value(camera, key, value)
+    }
+
+    /// Get the body's image review and auto-rotate settings
+    pub async fn get_review_settings(&self) -> std::result::Result<ReviewSettings, String> {
+        let camera = self.connected_camera().await?;
+        tokio::task::spawn_blocking(move || ReviewSettings {
+            image_review: Self::read_config_setting(&camera, IMAGE_REVIEW_KEYS),
+            auto_rotate: Self::read_config_setting(&camera, AUTO_ROTATE_KEYS),
+        })
+        .await
+        .map_err(|e| format!("Task join error: {}", e))
+    }
+
+    /// Set a body setting, resolving the key from its aliases
+    async fn set_aliased_setting(&self, keys: &'static [&'static str], label: &str, value: &str) -> std::result::Result<(), String> {
+        let camera = self.connected_camera().await?;
+        let label = label.to_string();
+        let value = value.to_string();
+        tokio::task::spawn_blocking(move || {
+            let setting = Self::read_config_setting(&camera, keys)
+                .ok_or_else(|| format!("Camera does not expose a {} setting", label))?;
+            Self::write_config_setting(&camera, &setting.key, &value)
+        })
+        .await
+        .map_err(|e| format!("Task join error: {}", e))?
+    }
+
+    /// Set the body's image review (e.g. "Off", "2 sec")
+    pub async fn set_image_review(&self, value: &str) -> std::result::Result<(), String> {
+        self.set_aliased_setting(IMAGE_REVIEW_KEYS, "image review", value).await
+    }
+
+    /// Set the body's auto-rotate (e.g. "Off", "On")
+    pub async fn set_auto_rotate(&self, value: &str) -> std::result::Result<(), String> {
+        self.set_aliased_setting(AUTO_ROTATE_KEYS, "auto-rotate", value).await
+    }
+
+    /// Get drive, AF and metering choices plus current values in one blocking round-trip
+    pub async fn get_mode_choices(&self) -> std::result::Result<ModeChoices, String> {
+        let camera = self.connected_camera().await?;
+        tokio::task::spawn_blocking(move || ModeChoices {
+            drive_mode: Self::read_config_setting(&camera, DRIVE_MODE_KEYS),
+            focus_mode: Self::read_config_setting(&camera, FOCUS_MODE_KEYS),
+            metering_mode: Self::read_config_setting(&camera, METERING_MODE_KEYS),
+        })
+        .await
+        .map_err(|e| format!("Task join error: {}", e))
+    }
+
+    /// Whether a drive mode choice is one of the body's silent (electronic shutter) variants
+    fn is_silent_drive(choice: &str) -> bool {
+        choice.to_lowercase().contains("silent")
+    }
+
+    /// Drive mode choice with the "silent" marker removed, for pairing silent and normal variants
+    fn drive_without_silent(choice: &str) -> String {
+        choice.to_lowercase()
+            .replace("silent", "")
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric())
+            .collect()
+    }
+
+    /// Read the current shutter type from a dedicated setting, or from silent drive modes
+    fn read_shutter_type(camera: &Camera) -> Option<ShutterType> {
+        if let Some(setting) = Self::read_config_setting(camera, SHUTTER_TYPE_KEYS) {
+            return ShutterType::from_choice(&setting.value);
+        }
+
+        let drive = Self::read_config_setting(camera, DRIVE_MODE_KEYS)?;
+        if !drive.choices.iter().any(|c| Self::is_silent_drive(c)) {
+            return None;
+        }
+        if Self::is_silent_drive(&drive.value) {
+            Some(ShutterType::Electronic)
+        } else {
+            Some(ShutterType::Mechanical)
+        }
+    }
+
+    /// Switch between mechanical and electronic shutter
+    /// Bodies that bundle the silent shutter into drive mode keep the drive behaviour
+    /// (single, continuous) and only swap between its silent and normal variant.
+    pub async fn set_shutter_type(&self, shutter_type: ShutterType) -> std::result::Result<(), String> {
+        let camera = self.connected_camera().await?;
+        tokio::task::spawn_blocking(move || {
+            if let Some(setting) = Self::read_config_setting(&camera, SHUTTER_TYPE_KEYS) {
+                let choice = setting.choices
+                    .iter()
+                    .find(|c| ShutterType::from_choice(c) == Some(shutter_type))
+                    .ok_or_else(|| format!("Camera does not offer a {:?} shutter (available: {})", shutter_type, setting.choices.join(", ")))?;
+                return Self::write_config_setting(&camera, &setting.key, choice);
+            }
+
+            let drive = Self::read_config_setting(&camera, DRIVE_MODE_KEYS)
+                .filter(|drive| drive.choices.iter().any(|c| Self::is_silent_drive(c)))
+                .ok_or_else(|| "Camera does not expose a shutter type setting".to_string())?;
+            let want_silent = match shutter_type {
+                ShutterType::Electronic => true,
+                ShutterType::Mechanical => false,
+                ShutterType::ElectronicFirstCurtain => {
+                    return Err("Camera only switches between silent and normal drive modes; electronic first curtain is not available".to_string());
+                }
+            };
+            if Self::is_silent_drive(&drive.value) == want_silent {
+                return Ok(());
+            }
+
+            let base = Self::drive_without_silent(&drive.value);
+            let candidates: Vec<&String> = drive.choices
+                .iter()
+                .filter(|c| Self::is_silent_drive(c) == want_silent)
+                .collect();
+            let choice = candidates
+                .iter()
+                .find(|c| Self::drive_without_silent(c) == base)
+                .or_else(|| candidates.first())
+                .ok_or_else(|| "No matching drive mode for the requested shutter type".to_string())?;
+            eprintln!("{} [Camera] Shutter type via drive mode: {} -> {}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), drive.value, choice);
+            Self::write_config_setting(&camera, &drive.key, choice)
+        })
+        .await
+        .map_err(|e| format!("Task join error: {}", e))??;
+
+        *self.cached_params.lock().await = None;
+        Ok(())
+    }
+
+    /// Get the in-camera aspect ratio with its choices
+    pub async fn get_aspect_ratio(&self) -> std::result::Result<Option<ConfigSetting>, String> {
+        let camera = self.connected_camera().await?;
+        tokio::task::spawn_blocking(move || Self::read_config_setting(&camera, ASPECT_RATIO_KEYS))
+            .await
+            .map_err(|e| format!("Task join error: {}", e))
+    }
+
+    /// Set the in-camera aspect ratio (e.g. "1:1", "3:2")
+    pub async fn set_aspect_ratio(&self, value: &str) -> std::result::Result<(), String> {
+        self.set_aliased_setting(ASPECT_RATIO_KEYS, "aspect ratio", value).await?;
+        *self.cached_params.lock().await = None;
+        Ok(())
+    }
+
+    /// Aspect ratio from the last params read, without another round of USB reads
+    async fn cached_aspect_ratio(&self) -> Option<String> {
+        self.cached_params
+            .lock()
+            .await
+            .as_ref()
+            .and_then(|(_, params)| params.aspect_ratio.clone())
+    }
+
+    /// The choice that turns a setting off ("Off", "None", "0")
+    fn off_choice(setting: &ConfigSetting) -> std::result::Result<&String, String> {
+        setting.choices
+            .iter()
+            .find(|choice| matches!(choice.trim().to_lowercase().as_str(), "off" | "none" | "0" | "disable" | "disabled"))
+            .ok_or_else(|| format!("No off choice for '{}'", setting.key))
+    }
+
+    /// Turn image review off so the body stays in capture mode between shots
+    async fn disable_image_review(&self) -> std::result::Result<(), String> {
+        let settings = self.get_review_settings().await?;
+        let Some(review) = settings.image_review else {
+            return Ok(());
+        };
+        let off = Self::off_choice(&review)?;
+        if review.value == *off {
+            return Ok(());
+        }
+        self.set_image_review(off).await
+    }
+
+    /// Parse an exposure delay choice ("Off", "0.2 s", "1 sec", "200ms") into seconds
+    fn exposure_delay_seconds(value: &str) -> Option<f32> {
+        let lower = value.trim().to_lowercase();
+        if matches!(lower.as_str(), "off" | "none" | "0") {
+            return Some(0.0);
+        }
+        let number = Self::parse_config_number(&lower)?;
+        Some(if lower.contains("ms") { number / 1000.0 } else { number })
+    }
+
+    /// Get the body's exposure delay with its choices; None where the body doesn't expose one
+    /// Interval timing can subtract `seconds` from the interval to keep frames on schedule.
+    pub async fn get_exposure_delay(&self) -> std::result::Result<Option<ExposureDelay>, String> {
+        let camera = self.connected_camera().await?;
+        tokio::task::spawn_blocking(move || {
+            Self::read_config_setting(&camera, EXPOSURE_DELAY_KEYS).map(|setting| ExposureDelay {
+                seconds: Self::exposure_delay_seconds(&setting.value),
+                setting,
+            })
+        })
+        .await
+        .map_err(|e| format!("Task join error: {}", e))
+    }
+
+    /// Set the body's exposure delay (e.g. "Off", "1 s")
+    pub async fn set_exposure_delay(&self, value: &str) -> std::result::Result<(), String> {
+        self.set_aliased_setting(EXPOSURE_DELAY_KEYS, "exposure delay", value).await
+    }
+
+    /// Stop the lens down to the set aperture, or open it back up
+    /// With live view running, the feed shows the stopped-down depth of field.
+    pub async fn dof_preview(&self, enable: bool) -> std::result::Result<(), String> {
+        let camera = self.connected_camera().await?;
+        tokio::task::spawn_blocking(move || {
+            let setting = Self::read_config_setting(&camera, DOF_PREVIEW_KEYS)
+                .ok_or_else(|| "Camera does not support remote depth-of-field preview".to_string())?;
+            let value = if enable {
+                setting.choices
+                    .iter()
+                    .find(|choice| matches!(choice.trim().to_lowercase().as_str(), "on" | "enable" | "enabled" | "1"))
+                    .ok_or_else(|| format!("No on choice for '{}'", setting.key))?
+            } else {
+                Self::off_choice(&setting)?
+            };
+            Self::write_config_setting(&camera, &setting.key, value)
+        })
+        .await
+        .map_err(|e| format!("Task join error: {}", e))?
+    }
+
+    /// Get the body's focus beep and AF-assist lamp settings
+    pub async fn get_quiet_settings(&self) -> std::result::Result<QuietSettings, String> {
+        let camera = self.connected_camera().await?;
+        tokio::task::spawn_blocking(move || QuietSettings {
+            beep: Self::read_config_setting(&camera, BEEP_KEYS),
+            af_assist: Self::read_config_setting(&camera, AF_ASSIST_KEYS),
+        })
+        .await
+        .map_err(|e| format!("Task join error: {}", e))
+    }
+
+    /// Set the body's focus beep (e.g. "Off", "On")
+    pub async fn set_beep(&self, value: &str) -> std::result::Result<(), String> {
+        self.set_aliased_setting(BEEP_KEYS, "beep", value).await
+    }
+
+    /// Set the body's AF-assist lamp (e.g. "Off", "On")
+    pub async fn set_af_assist(&self, value: &str) -> std::result::Result<(), String> {
+        self.set_aliased_setting(AF_ASSIST_KEYS, "AF-assist", value).await
+    }
+
+    /// Turn off the focus beep and AF-assist lamp, skipping whichever the body doesn't expose
+    async fn silence_camera(&self) -> std::result::Result<(), String> {
+        let settings = self.get_quiet_settings().await?;
+        if let Some(beep) = settings.beep {
+            let off = Self::off_choice(&beep)?;
+            if beep.value != *off {
+                self.set_beep(off).await?;
+            }
+        }
+        if let Some(af_assist) = settings.af_assist {
+            let off = Self::off_choice(&af_assist)?;
+            if af_assist.value != *off {
+                self.set_af_assist(off).await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Get the normalized exposure program from the mode dial
+    pub async fn get_exposure_program(&self) -> std::result::Result<Option<ExposureProgram>, String> {
+        let camera = self.connected_camera().await?;
+        tokio::task::spawn_blocking(move || {
+            Self::get_radio_value(&camera, SHOOTING_MODE_KEYS)
+                .and_then(|mode| ExposureProgram::from_mode(&mode))
+        })
+        .await
+        .map_err(|e| format!("Task join error: {}", e))
+    }
+
+    /// Read the exposure meter deviation where the body surfaces it
+    fn read_exposure_meter(camera: &Camera) -> Option<f32> {
+        for key in EXPOSURE_METER_KEYS {
+            if let Ok(widget) = camera.config_key::<gphoto2::widget::RangeWidget>(key).wait() {
+                return Some(widget.value());
+            }
+            if let Ok(widget) = camera.config_key::<gphoto2::widget::TextWidget>(key).wait() {
+                if let Ok(value) = widget.value().trim().trim_start_matches('+').parse::<f32>() {
+                    return Some(value);
+                }
+            }
+        }
+        None
+    }
+
+    /// Get the exposure meter reading; None outside manual mode or on bodies without a meter readout
+    pub async fn get_exposure_meter(&self) -> std::result::Result<Option<f32>, String> {
+        let camera = self.connected_camera().await?;
+        tokio::task::spawn_blocking(move || {
+            let manual = Self::get_radio_value(&camera, SHOOTING_MODE_KEYS)
+                .map_or(false, |mode| Self::is_manual_mode(&mode));
+            if manual { Self::read_exposure_meter(&camera) } else { None }
+        })
+        .await
+        .map_err(|e| format!("Task join error: {}", e))
+    }
+
+    /// Spot-meter at a normalized point (0..1 from the top left) and return the metered deviation in EV
+    /// Moves the AF area there, which the body's spot meter follows, switches to spot metering
+    /// for the reading and then restores the previous metering mode and AF area. Like the regular
+    /// meter the reading is relative to the current settings, so it is None outside manual mode.
+    /// The point is mapped into the AF area's coordinates, which are live view image pixels; a
+    /// body that doesn't report its current AF area gets it back at the frame center.
+    pub async fn spot_meter(&self, x: f32, y: f32) -> std::result::Result<Option<f32>, String> {
+        if !(0.0..=1.0).contains(&x) || !(0.0..=1.0).contains(&y) {
+            return Err(format!("Spot meter point ({}, {}) is outside the frame", x, y));
+        }
+        let _operation = self.begin_operation("spot meter").await?;
+        let camera = self.connected_camera().await?;
+
+        tokio::task::spawn_blocking(move || {
+            let manual = Self::get_radio_value(&camera, SHOOTING_MODE_KEYS)
+                .map_or(false, |mode| Self::is_manual_mode(&mode));
+            if !manual {
+                return Ok(None);
+            }
+
+            let (af_key, original_area) = AF_AREA_KEYS
+                .iter()
+                .find_map(|key| {
+                    camera.config_key::<gphoto2::widget::TextWidget>(key)
+                        .wait()
+                        .ok()
+                        .map(|widget| (*key, widget.value().trim().to_string()))
+                })
+                .ok_or_else(|| "Camera does not support remote spot metering".to_string())?;
+            let metering = Self::read_config_setting(&camera, METERING_MODE_KEYS)
+                .ok_or_else(|| "Camera does not support remote spot metering".to_string())?;
+            let spot = metering.choices
+                .iter()
+                .find(|choice| choice.to_lowercase().contains("spot"))
+                .ok_or_else(|| "Camera does not offer spot metering".to_string())?;
+
+            let (width, height) = Self::af_area_range(&camera)?;
+            let reports_area = original_area
+                .split_once('x')
+                .map_or(false, |(x, y)| x.trim().parse::<u32>().is_ok() && y.trim().parse::<u32>().is_ok());
+            let original_area = if reports_area { original_area } else { format!("{}x{}", width / 2, height / 2) };
+            let point = format!("{}x{}", (x * (width - 1) as f32).round() as u32, (y * (height - 1) as f32).round() as u32);
+            Self::set_af_area(&camera, af_key, &point)?;
+
+            let reading = (|| {
+                if metering.value != *spot {
+                    Self::set_radio_value(&camera, &metering.key, spot)?;
+                }
+                // Give the meter a moment to settle on the new point
+                std::thread::sleep(Duration::from_millis(300));
+                let reading = Self::read_exposure_meter(&camera);
+
+                if metering.value != *spot {
+                    if let Err(e) = Self::set_radio_value(&camera, &metering.key, &metering.value) {
+                        eprintln!("{} [Camera] Failed to restore metering mode '{}': {}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), metering.value, e);
+                    }
+                }
+                Ok::<Option<f32>, String>(reading)
+            })();
+
+            if let Err(e) = Self::set_af_area(&camera, af_key, &original_area) {
+                eprintln!("{} [Camera] Failed to restore AF area '{}': {}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), original_area, e);
+            }
+            reading
+        })
+        .await
+        .map_err(|e| format!("Task join error: {}", e))?
+    }
+
+    /// Size of the AF area coordinate space, which is the live view image
+    fn af_area_range(camera: &Camera) -> std::result::Result<(u32, u32), String> {
+        let context = Self::new_context()?;
+        let frame = camera.capture_preview()
+            .wait()
+            .map_err(|e| format!("Failed to capture preview for the AF area: {}", e))?
+            .get_data(&context)
+            .wait()
+            .map_err(|e| format!("Failed to read preview for the AF area: {}", e))?;
+        let (width, height) = image_crate::ImageReader::new(std::io::Cursor::new(&frame[..]))
+            .with_guessed_format()
+            .map_err(|e| format!("Failed to read preview for the AF area: {}", e))?
+            .into_dimensions()
+            .map_err(|e| format!("Failed to read preview for the AF area: {}", e))?;
+        if width == 0 || height == 0 {
+            return Err("Live view frame is empty".to_string());
+        }
+        Ok((width, height))
+    }
+
+    /// Move the AF area to an "XxY" position
+    fn set_af_area(camera: &Camera, key: &str, point: &str) -> std::result::Result<(), String> {
+        let widget = camera.config_key::<gphoto2::widget::TextWidget>(key)
+            .wait()
+            .map_err(|e| format!("Failed to get config '{}': {}", key, e))?;
+        widget.set_value(point)
+            .map_err(|e| format!("Failed to set AF area '{}': {}", point, e))?;
+        camera.set_config(&widget)
+            .wait()
+            .map_err(|e| format!("Failed to move AF area: {}", e))
+    }
+
+    /// Choose where remote captures are stored, setting the body's capture target to match
+    pub async fn set_capture_destination(&self, destination: CaptureDestination) -> std::result::Result<(), String> {
+        let camera = self.connected_camera().await?;
+        tokio::task::spawn_blocking(move || {
+            let setting = Self::read_config_setting(&camera, CAPTURE_TARGET_KEYS)
+                .ok_or_else(|| "Camera does not expose a capture target setting".to_string())?;
+            let choices: Vec<(String, String)> = setting.choices
+                .iter()
+                .map(|choice| (choice.clone(), choice.to_lowercase()))
+                .collect();
+            // Sony offers "card+sdram" for both; Canon and Nikon write the card and allow downloads anyway
+            let card = choices.iter().find(|(_, lower)| lower.contains("card") && !lower.contains("ram"));
+            let choice = match destination {
+                CaptureDestination::PcOnly => choices.iter().find(|(_, lower)| lower.contains("ram") && !lower.contains("card")),
+                CaptureDestination::CardOnly => card,
+                CaptureDestination::Both => choices.iter().find(|(_, lower)| lower.contains("card") && lower.contains("ram")).or(card),
+            }
+            .ok_or_else(|| format!("Camera has no capture target for {:?}", destination))?;
+            Self::write_config_setting(&camera, &setting.key, &choice.0)
+        })
+        .await
+        .map_err(|e| format!("Task join error: {}", e))??;
+
+        self.card_only.store(destination == CaptureDestination::CardOnly, Ordering::Relaxed);
+        *self.cached_params.lock().await = None;
+        eprintln!("{} [Camera] Capture destination set to {:?}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), destination);
+        Ok(())
+    }
+
+    /// Fire the shutter for a card-only capture and describe the file left on the card
+    /// `file_path` is the card path; nothing is downloaded, so the local-file fields stay empty.
+    async fn capture_to_card(&self, app: &AppHandle, camera: Camera, skip_af: bool, session_id: Option<String>) -> std::result::Result<CaptureResult, String> {
+        let options = self.options.lock().await.clone();
+        let _io = self.camera_io_permit().await;
+        let (card_path, camera_file_size, dimensions) = tokio::task::spawn_blocking(move || {
+            let image_path = Self::fire_shutter(&camera, skip_af, &options)?;
+            let (folder, name) = (image_path.folder().to_string(), image_path.name().to_string());
+            let size = Self::camera_file_size(&camera, &folder, &name);
+            let dimensions = sensor_dimensions::fallback_dimensions(&camera.abilities().model().to_string());
+            Ok::<(String, Option<u64>, (u32, u32)), String>((format!("{}/{}", folder.trim_end_matches('/'), name), size, dimensions))
+        })
+        .await
+        .map_err(|e| format!("Task join error: {}", e))?
+        .map_err(|e| {
+            self.record_capture_error(app, &e);
+            format!("Capture error: {}", e)
+        })?;
+        self.consecutive_busy.store(0, Ordering::Relaxed);
+        if let Some((folder, name)) = card_path.rsplit_once('/') {
+            self.remember_camera_file(folder, name);
+        }
+
+        let result = CaptureResult {
+            format: CaptureFormat::from_paths(&[&card_path]),
+            previous_capture: None,
+            session_id,
+            orientation: None,
+            file_size: 0,
+            raw_file_size: None,
+            jpg_file_size: None,
+            camera_file_size,
+            checksum: None,
+            capture_id: None,
+            sequence_id: None,
+            aspect_ratio: self.cached_aspect_ratio().await,
+            calibration: None,
+            af_point: self.get_af_point().await.ok().flatten(),
+            file_path: card_path,
+            raw_path: None,
+            jpg_path: None,
+            preview_path: None,
+            width: dimensions.0,
+            height: dimensions.1,
+        };
+        app.emit("camera:captured_to_card", &result).ok();
+        Ok(result)
+    }
+
+    /// Read the body's time zone and DST settings
+    pub async fn get_time_zone(&self) -> std::result::Result<TimeZoneSettings, String> {
+        let camera = self.connected_camera().await?;
+        tokio::task::spawn_blocking(move || TimeZoneSettings {
+            timezone: Self::read_config_setting(&camera, TIMEZONE_KEYS),
+            dst: Self::read_config_setting(&camera, DST_KEYS),
+            utc_clock: CLOCK_UTC_KEYS.iter().any(|key| camera.config_key::<gphoto2::widget::DateWidget>(key).wait().is_ok()),
+        })
+        .await
+        .map_err(|e| format!("Task join error: {}", e))
+    }
+
+    /// Set the body's time zone (a choice from get_time_zone) and/or DST
+    pub async fn set_time_zone(&self, timezone: Option<String>, dst: Option<bool>) -> std::result::Result<(), String> {
+        if let Some(timezone) = timezone {
+            self.set_aliased_setting(TIMEZONE_KEYS, "time zone", &timezone).await?;
+        }
+        if let Some(dst) = dst {
+            self.set_aliased_setting(DST_KEYS, "daylight saving", if dst { "On" } else { "Off" }).await?;
+        }
+        Ok(())
+    }
+
+    /// Set the body's clock from the host, optionally with the host's time zone and DST
+    /// Bodies with a UTC clock get UTC and need the right zone for correct local EXIF times;
+    /// bodies with only a local clock get the host's local wall time, which is what they record.
+    pub async fn sync_camera_time(&self, set_timezone: bool) -> std::result::Result<ClockSync, String> {
+        let camera = self.connected_camera().await?;
+        let sync = tokio::task::spawn_blocking(move || {
+            let now = chrono::Utc::now().timestamp();
+            let utc_key = CLOCK_UTC_KEYS.iter().find(|key| camera.config_key::<gphoto2::widget::DateWidget>(key).wait().is_ok());
+            let local_key = CLOCK_LOCAL_KEYS.iter().find(|key| camera.config_key::<gphoto2::widget::DateWidget>(key).wait().is_ok());
+            let (key, utc_clock) = match (utc_key, local_key) {
+                (Some(key), _) => (*key, true),
+                (None, Some(key)) => (*key, false),
+                (None, None) => return Err("Camera does not expose a settable clock".to_string()),
+            };
+            // libgphoto2 renders a local clock from the timestamp in the host's zone
+            let widget = camera.config_key::<gphoto2::widget::DateWidget>(key)
+                .wait()
+                .map_err(|e| format!("Failed to get config '{}': {}", key, e))?;
+            widget.set_timestamp(now as _);
+            camera.set_config(&widget)
+                .wait()
+                .map_err(|e| format!("Failed to set camera clock: {}", e))?;
+
+            let mut sync = ClockSync { utc_clock, timezone: None, dst: None };
+            if !set_timezone {
+                return Ok(sync);
+            }
+            let (standard_offset, dst_active) = Self::host_utc_offset();
+            if let Some(setting) = Self::read_config_setting(&camera, DST_KEYS) {
+                let wanted = if dst_active { "On" } else { "Off" };
+                if setting.choices.iter().any(|choice| choice == wanted) {
+                    Self::write_config_setting(&camera, &setting.key, wanted)?;
+                    sync.dst = Some(dst_active);
+                }
+            }
+            // Without a DST setting the zone has to carry the full current offset
+            let offset = if sync.dst.is_some() || !dst_active { standard_offset } else { standard_offset + 60 };
+            if let Some(setting) = Self::read_config_setting(&camera, TIMEZONE_KEYS) {
+                let choice = setting.choices
+                    .iter()
+                    .find(|choice| Self::parse_utc_offset(choice) == Some(offset))
+                    .ok_or_else(|| format!("Camera has no time zone for UTC offset {} minutes", offset))?;
+                Self::write_config_setting(&camera, &setting.key, choice)?;
+                sync.timezone = Some(choice.clone());
+            } else if utc_clock {
+                eprintln!("{} [Camera] Body keeps UTC but exposes no time zone; set it on the body", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"));
+            }
+            Ok(sync)
+        })
+        .await
+        .map_err(|e| format!("Task join error: {}", e))??;
+
+        eprintln!("{} [Camera] Clock synced ({} clock, zone {:?}, DST {:?})", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), if sync.utc_clock { "UTC" } else { "local" }, sync.timezone, sync.dst);
+        Ok(sync)
+    }
+
+    /// Host's standard UTC offset in minutes and whether DST is in effect now
+    /// DST is inferred from the offset being above the smaller of the January and July offsets.
+    fn host_utc_offset() -> (i32, bool) {
+        use chrono::{Datelike, TimeZone};
+        let now = chrono::Local::now();
+        let offset_at = |month: u32| {
+            chrono::Local
+                .with_ymd_and_hms(now.year(), month, 1, 12, 0, 0)
+                .single()
+                .map(|date| date.offset().local_minus_utc() / 60)
+        };
+        let current = now.offset().local_minus_utc() / 60;
+        let standard = match (offset_at(1), offset_at(7)) {
+            (Some(january), Some(july)) => january.min(july),
+            _ => current,
+        };
+        (standard, current > standard)
+    }
+
+    /// Parse the UTC offset in minutes out of a time zone choice ("+09:00 Tokyo", "UTC-5", "GMT")
+    fn parse_utc_offset(choice: &str) -> Option<i32> {
+        let chars: Vec<char> = choice.chars().collect();
+        let sign_index = chars.windows(2).position(|pair| (pair[0] == '+' || pair[0] == '-') && pair[1].is_ascii_digit());
+        let Some(index) = sign_index else {
+            let upper = choice.to_uppercase();
+            return (upper.contains("UTC") || upper.contains("GMT")).then_some(0);
+        };
+        let sign = if chars[index] == '-' { -1 } else { 1 };
+        let rest: String = chars[index + 1..].iter().take_while(|c| c.is_ascii_digit() || **c == ':').collect();
+        let (hours, minutes) = rest.split_once(':').unwrap_or((&rest, "0"));
+        let hours: i32 = hours.parse().ok()?;
+        let minutes: i32 = if minutes.is_empty() { 0 } else { minutes.parse().ok()? };
+        Some(sign * (hours * 60 + minutes))
+    }
+
+    /// Read the selected AF point or zone; None on bodies that don't report it
+    pub async fn get_af_point(&self) -> std::result::Result<Option<String>, String> {
+        let camera = self.connected_camera().await?;
+        tokio::task::spawn_blocking(move || {
+            Self::read_config_text(&camera, AF_POINT_KEYS)
+                .map(|point| point.trim().to_string())
+                .filter(|point| !point.is_empty())
+        })
+        .await
+        .map_err(|e| format!("Task join error: {}", e))
+    }
+
+    /// Advanced: read a raw PTP device property by code
+    /// Only properties the device lists as supported and gphoto2 doesn't model as a named
+    /// setting are reachable; anything else returns None.
+    pub async fn get_ptp_property(&self, code: u16) -> std::result::Result<Option<PtpValue>, String> {
+        let camera = self.connected_camera().await?;
+        tokio::task::spawn_blocking(move || Self::read_ptp_property(&camera, code))
+            .await
+            .map_err(|e| format!("Task join error: {}", e))
+    }
+
+    /// Advanced: write a raw PTP device property by code
+    /// Bypasses every check the named settings have, so a wrong value can leave the body in an
+    /// odd state. The code must be in the device's supported property list and writable.
+    pub async fn set_ptp_property(&self, code: u16, value: &str) -> std::result::Result<(), String> {
+        let camera = self.connected_camera().await?;
+        let value = value.to_string();
+        eprintln!("{} [Camera] Setting raw PTP property 0x{:04x} to '{}'", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), code, value);
+        tokio::task::spawn_blocking(move || Self::write_ptp_property(&camera, code, &value))
+            .await
+            .map_err(|e| format!("Task join error: {}", e))??;
+
+        // Cached params no longer reflect the camera
+        *self.cached_params.lock().await = None;
+        Ok(())
+    }
+
+    fn ptp_property_key(code: u16) -> String {
+        format!("{:04x}", code)
+    }
+
+    /// Find a PTP property among the device's supported properties
+    fn read_ptp_property(camera: &Camera, code: u16) -> Option<PtpValue> {
+        let key = Self::ptp_property_key(code);
+        let group = camera.config_key::<gphoto2::widget::GroupWidget>(PTP_PROPERTY_GROUP).wait().ok()?;
+        let widget = group.children_iter().find(|widget| widget.name().eq_ignore_ascii_case(&key))?;
+        let (value, choices) = match &widget {
+            gphoto2::widget::Widget::Text(widget) => (widget.value().to_string(), Vec::new()),
+            gphoto2::widget::Widget::Range(widget) => (widget.value().to_string(), Vec::new()),
+            gphoto2::widget::Widget::Radio(widget) => (widget.choice().to_string(), widget.choices_iter().map(|c| c.to_string()).collect()),
+            gphoto2::widget::Widget::Toggle(widget) => {
+                let value = if widget.toggled() == Some(true) { "On" } else { "Off" };
+                (value.to_string(), vec!["Off".to_string(), "On".to_string()])
+            }
+            _ => return None,
+        };
+        Some(PtpValue {
+            code,
+            label: widget.label().to_string(),
+            value,
+            choices,
+            readonly: widget.readonly(),
+        })
+    }
+
+    fn write_ptp_property(camera: &Camera, code: u16, value: &str) -> std::result::Result<(), String> {
+        let property = Self::read_ptp_property(camera, code)
+            .ok_or_else(|| format!("PTP property 0x{:04x} is not in the camera's supported list", code))?;
+        if property.readonly {
+            return Err(format!("PTP property 0x{:04x} is readonly", code));
+        }
+        if !property.choices.is_empty() && !property.choices.iter().any(|choice| choice == value) {
+            return Err(format!("Value '{}' not available for PTP property 0x{:04x}", value, code));
+        }
+
+        let key = Self::ptp_property_key(code);
+        let widget = camera.config_key::<gphoto2::widget::Widget>(&key)
+            .wait()
+            .map_err(|e| format!("Failed to get config '{}': {}", key, e))?;
+        match &widget {
+            gphoto2::widget::Widget::Text(widget) => widget.set_value(value)
+                .map_err(|e| format!("Failed to set value '{}' for '{}': {}", value, key, e))?,
+            gphoto2::widget::Widget::Range(widget) => {
+                let number = value.trim().parse::<f32>()
+                    .map_err(|_| format!("Value '{}' for '{}' is not a number", value, key))?;
+                widget.set_value(number)
+                    .map_err(|e| format!("Failed to set value '{}' for '{}': {}", value, key, e))?
+            }
+            gphoto2::widget::Widget::Radio(widget) => widget.set_choice(value)
+                .map_err(|e| format!("Failed to set choice '{}' for '{}': {}", value, key, e))?,
+            gphoto2::widget::Widget::Toggle(widget) => widget.set_toggled(value.eq_ignore_ascii_case("on")),
+            _ => return Err(format!("PTP property 0x{:04x} has an unsupported value type", code)),
+        }
+        camera.set_config(&widget)
+            .wait()
+            .map_err(|e| format!("Failed to apply config '{}': {}", key, e))
+    }
+
+    /// Read the body's live orientation sensor where exposed
+    fn read_orientation(camera: &Camera) -> Option<Orientation> {
+        for key in ORIENTATION_KEYS {
+            if let Ok(widget) = camera.config_key::<gphoto2::widget::TextWidget>(key).wait() {
+                return Orientation::from_config_value(&widget.value());
+            }
+            if let Ok(widget) = camera.config_key::<gphoto2::widget::RadioWidget>(key).wait() {
+                return Orientation::from_config_value(&widget.choice());
+            }
+        }
+        None
+    }
+
+    /// Read the EXIF orientation tag from a downloaded file
+    fn read_exif_orientation(file_path: &str) -> Option<Orientation> {
+        let file = std::fs::File::open(file_path).ok()?;
+        let exif = exif::Reader::new()
+            .read_from_container(&mut std::io::BufReader::new(file))
+            .ok()?;
+        exif.get_field(exif::Tag::Orientation, exif::In::PRIMARY)
+            .and_then(|field| field.value.get_uint(0))
+            .and_then(Orientation::from_exif)
+    }
+
+    /// Get the camera's current orientation
+    pub async fn get_orientation(&self) -> std::result::Result<Option<Orientation>, String> {
+        let camera = self.connected_camera().await?;
+        tokio::task::spawn_blocking(move || Self::read_orientation(&camera))
+            .await
+            .map_err(|e| format!("Task join error: {}", e))
+    }
+
+    /// Orientation for a finished capture: live sensor first, then the file's EXIF
+    async fn capture_orientation(&self, file_path: &str) -> Option<Orientation> {
+        if let Ok(Some(orientation)) = self.get_orientation().await {
+            return Some(orientation);
+        }
+        let file_path = file_path.to_string();
+        tokio::task::spawn_blocking(move || Self::read_exif_orientation(&file_path))
+            .await
+            .ok()
+            .flatten()
+    }
+
+    /// Port path of a camera (e.g. "usb:001,004"), falling back to the bus type
+    fn camera_port(camera: &Camera) -> String {
+        camera.port_info()
+            .map(|info| info.path().to_string())
+            .unwrap_or_else(|_| "usb".to_string())
+    }
+
+    /// Step the lens focus toward near or far
+    /// Canon takes "Near 1".."Far 3" radio choices; Nikon a signed range where positive is far.
+    /// Choice labels follow libgphoto2's Canon EOS and Nikon drivers; untested on other bodies.
+    /// Both need live view and AF on the lens, so MF mode is rejected up front.
+    pub async fn drive_focus(&self, direction: FocusDirection, step: FocusStep) -> std::result::Result<(), String> {
+        let camera = self.connected_camera().await?;
+        if !self.liveview_active.load(Ordering::Relaxed) {
+            return Err("Focus drive needs live view running".to_string());
+        }
+
+        tokio::task::spawn_blocking(move || {
+            if let Some(mode) = Self::get_radio_value(&camera, FOCUS_MODE_KEYS) {
+                let lower = mode.to_lowercase();
+                if lower.contains("manual") || lower == "mf" {
+                    return Err(format!("Focus drive is not available in focus mode '{}'", mode));
+                }
+            }
+
+            if let Ok(widget) = camera.config_key::<gphoto2::widget::RadioWidget>("manualfocusdrive").wait() {
+                let prefix = match direction {
+                    FocusDirection::Near => "near",
+                    FocusDirection::Far => "far",
+                };
+                let wanted = format!("{} {}", prefix, step.level());
+                let choice = widget.choices_iter()
+                    .find(|choice| choice.trim().to_lowercase() == wanted)
+                    .ok_or_else(|| format!("Camera has no focus drive step '{}'", wanted))?;
+                return Self::set_radio_value(&camera, "manualfocusdrive", &choice);
+            }
+
+            let widget = camera.config_key::<gphoto2::widget::RangeWidget>("manualfocusdrive")
+                .wait()
+                .map_err(|_| "Camera does not support manual focus drive".to_string())?;
+            let (range, _) = widget.range_and_step();
+            let amount = match direction {
+                FocusDirection::Near => -step.range_amount(),
+                FocusDirection::Far => step.range_amount(),
+            };
+            let amount = amount.clamp(*range.start(), *range.end());
+            widget.set_value(amount)
+                .map_err(|e| format!("Failed to set focus drive to {}: {}", amount, e))?;
+            camera.set_config(&widget)
+                .wait()
+                .map_err(|e| format!("Failed to drive focus: {}", e))
+        })
+        .await
+        .map_err(|e| format!("Task join error: {}", e))?
+    }
+
+    /// Read the body's focus confirmation state
+    /// Canon exposes a `focusinfo` text during live view; other bodies use a toggle
+    fn read_focus_confirmed(camera: &Camera) -> Option<bool> {
+        for key in FOCUS_CONFIRM_KEYS {
+            if let Ok(widget) = camera.config_key::<gphoto2::widget::ToggleWidget>(key).wait() {
+                if let Some(toggled) = widget.toggled() {
+                    return Some(toggled);
+                }
+            }
+        }
+
+        camera.config_key::<gphoto2::widget::TextWidget>("focusinfo")
+            .wait()
+            .ok()
+            .map(|widget| {
+                let info = widget.value().to_lowercase();
+                info.contains("in focus") || info.contains("focused")
+            })
+    }
+
+    /// Connect to the camera on `port` (from list_cameras), or the first available one
+    /// A picked port is remembered so auto-connect reopens that body rather than whichever
+    /// enumerates first; connecting without a port clears it.
+    pub async fn connect_camera(&self, app: AppHandle, port: Option<String>) -> std::result::Result<CameraParams, String> {
+        // A manual connect re-enables auto-connect
+        self.auto_connect_cancelled.store(false, Ordering::Relaxed);
+
+        let release_usb = self.options.lock().await.release_usb_on_connect;
+        let picked = port.is_some();
+        let wanted_port = port;
+        let (camera, _model, port) = tokio::task::spawn_blocking(move || {
+            if release_usb {
+                Self::release_usb_claims();
+            }
+
+            let context = Self::new_context()?;
+
+            let camera = match wanted_port {
+                Some(port) => Self::open_camera_on_port(&context, &port)?
+                    .ok_or_else(|| format!("No camera found on port {}", port))?,
+                None => context.autodetect_camera()
+                    .wait()
+                    .map_err(|e| {
+                        if release_usb && Self::is_usb_claim_error(&e.to_string()) {
+                            format!("Camera is still claimed by another process after releasing gvfs: {}", e)
+                        } else {
+                            format!("Failed to autodetect: {}", e)
+                        }
+                    })?,
+            };
+
+            // Get camera info
+            let abilities = camera.abilities();
+            let model = abilities.model().to_string();
+            let port = Self::camera_port(&camera);
+
+            Ok::<(Camera, String, String), String>((camera, model, port))
+        })
+        .await
+        .map_err(|e| format!("Task join error: {}", e))??;
+
+        *self.camera.lock().await = Some(camera);
+        *self.active_port.lock().await = Some(port.clone());
+        *self.preferred_port.lock().await = picked.then(|| port.clone());
+        self.on_connected(&app, ConnectionReason::User).await;
+
+        // Get initial parameters, after any auto-apply profile changed them
+        let params = self.get_camera_params_internal().await?;
+
+        // Emit connected event
+        app.emit("camera:status", "Connected").ok();
+        eprintln!("{} [Camera] Connected to {}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), params.model);
+
+        Ok(params)
+    }
+
+    /// Emit `camera:transfer_stats` with the throughput of the connection's first download
+    /// One sample is enough to tell a USB 2 port apart from USB 3 when diagnosing slow tethering.
+    fn report_transfer_stats(&self, app: &AppHandle, bytes: u64, elapsed: Duration) {
+        if bytes == 0 || self.transfer_stats_reported.swap(true, Ordering::Relaxed) {
+            return;
+        }
+        let seconds = elapsed.as_secs_f64().max(0.001);
+        let mb_per_sec = bytes as f64 / seconds / 1_000_000.0;
+        eprintln!("{} [Camera] First download: {} bytes in {:.2}s ({:.1} MB/s)", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), bytes, seconds, mb_per_sec);
+        app.emit("camera:transfer_stats", serde_json::json!({
+            "bytes": bytes,
+            "seconds": seconds,
+            "mbPerSec": mb_per_sec,
+        })).ok();
+    }
+
+    /// Per-connection setup shared by manual connect, auto-connect and failover
+    async fn on_connected(&self, app: &AppHandle, reason: ConnectionReason) {
+        *self.capabilities.lock().await = None;
+        self.sleeping.store(false, Ordering::Relaxed);
+        self.transfer_stats_reported.store(false, Ordering::Relaxed);
+        *self.thermal_status.lock().unwrap() = ThermalStatus::Normal;
+        let serial = self.connected_serial_number().await;
+        {
+            let mut previous = self.camera_serial.lock().await;
+            if serial.is_some() && *previous == serial {
+                eprintln!("{} [Camera] Same camera reconnected, keeping session state", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"));
+            } else {
+                // Card-only was chosen for the previous body
+                self.card_only.store(false, Ordering::Relaxed);
+            }
+            *previous = serial;
+        }
+        self.record_connection_event(app, true, reason, None).await;
+        if let Err(e) = self.get_capabilities().await {
+            eprintln!("{} [Camera] Failed to probe capabilities: {}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), e);
+        }
+        self.warn_unresolved_aliases(app).await;
+        self.recover_downloads(app).await;
+        self.apply_model_profile(app).await;
+    }
+
+    /// Read the body's serial number, if it reports one
+    fn read_serial_number(camera: &Camera) -> Option<String> {
+        Self::read_config_text(camera, SERIAL_NUMBER_KEYS)
+            .map(|serial| serial.trim().to_string())
+            .filter(|serial| !serial.is_empty())
+    }
+
+    /// Serial number of the connected camera
+    async fn connected_serial_number(&self) -> Option<String> {
+        let camera = self.connected_camera().await.ok()?;
+        tokio::task::spawn_blocking(move || Self::read_serial_number(&camera))
+            .await
+            .ok()
+            .flatten()
+    }
+
+    /// Add a connect/disconnect to the history, emit it as `camera:connection_event` and
+    /// append it to the configured log file
+    /// Call before the camera handle is cleared, so the model is still known.
+    async fn record_connection_event(&self, app: &AppHandle, connected: bool, reason: ConnectionReason, detail: Option<String>) {
+        let model = match self.camera.lock().await.as_ref() {
+            Some(camera) => Some(camera.abilities().model().to_string()),
+            None => None,
+        };
+        let event = ConnectionEvent {
+            timestamp: chrono::Local::now().to_rfc3339(),
+            connected,
+            reason,
+            model,
+            port: self.active_port.lock().await.clone(),
+            detail,
+        };
+
+        if let Some(path) = self.options.lock().await.connection_log_path.clone() {
+            let line = serde_json::to_string(&event).unwrap_or_default();
+            let written = std::fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&path)
+                .and_then(|mut file| std::io::Write::write_all(&mut file, format!("{}\n", line).as_bytes()));
+            if let Err(e) = written {
+                eprintln!("{} [Camera] Failed to write connection log: {}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), e);
+            }
+        }
+
+        app.emit("camera:connection_event", &event).ok();
+        let mut history = self.connection_history.lock().unwrap();
+        history.push_back(event);
+        while history.len() > CONNECTION_HISTORY_LIMIT {
+            history.pop_front();
+        }
+    }
+
+    /// Connect/disconnect events since startup, oldest first
+    pub fn get_connection_history(&self) -> Vec<ConnectionEvent> {
+        self.connection_history.lock().unwrap().iter().cloned().collect()
+    }
+
+    /// Apply the auto-apply profile configured for the connected model, emitting `camera:profile_applied`
+    async fn apply_model_profile(&self, app: &AppHandle) {
+        let profiles = self.options.lock().await.auto_apply_profiles.clone();
+        if profiles.is_empty() {
+            return;
+        }
+        let Ok(camera) = self.connected_camera().await else {
+            return;
+        };
+        let model = camera.abilities().model().to_string();
+        // A profile for this exact body (by serial) wins over one for its model
+        let serial = self.camera_serial.lock().await.clone();
+        let by_serial = serial.and_then(|serial| profiles.get(&serial).cloned());
+        let Some(preset) = by_serial.or_else(|| {
+            profiles
+                .into_iter()
+                .find(|(profile_model, _)| profile_model.eq_ignore_ascii_case(&model))
+                .map(|(_, preset)| preset)
+        })
+        else {
+            return;
+        };
+
+        let name = preset.name.clone();
+        match self.apply_preset_atomic(preset).await {
+            Ok(()) => {
+                eprintln!("{} [Camera] Applied profile '{}' for {}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), name, model);
+                app.emit("camera:profile_applied", serde_json::json!({
+                    "model": model,
+                    "profile": name,
+                })).ok();
+            }
+            Err(e) => {
+                eprintln!("{} [Camera] Failed to apply profile '{}' for {}: {}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), name, model, e);
+            }
+        }
+    }
+
+    /// Finish downloads a crash interrupted, emitting `camera:recovered` with the recovered paths
+    async fn recover_downloads(&self, app: &AppHandle) {
+        let Ok(camera) = self.connected_camera().await else {
+            return;
+        };
+        let options = self.options.lock().await.clone();
+        let mut dirs = vec![self.capture_dir.clone()];
+        let extra_dirs = [self.current_download_folder.lock().await.clone(), options.raw_dir, options.jpg_dir];
+        for dir in extra_dirs.into_iter().flatten().map(PathBuf::from) {
+            if !dirs.contains(&dir) {
+                dirs.push(dir);
+            }
+        }
+
+        let _io = self.camera_io_permit().await;
+        match tokio::task::spawn_blocking(move || Self::recover_partial_downloads(&camera, &dirs)).await {
+            Ok(recovered) if !recovered.is_empty() => {
+                app.emit("camera:recovered", &recovered).ok();
+            }
+            Ok(_) => {}
+            Err(e) => {
+                eprintln!("{} [Camera] Download recovery task failed: {}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), e);
+            }
+        }
+    }
+
+    /// Probe the config tree and abilities for supported features
+    fn probe_capabilities(camera: &Camera) -> CameraCapabilities {
+        let has_key = |key: &str| camera.config_key::<gphoto2::widget::Widget>(key).wait().is_ok();
+        let operations = camera.abilities().camera_operations();
+
+        // Bulb is either its own toggle or a shutter speed choice
+        let bulb = has_key("bulb")
+            || SHUTTER_SPEED_KEYS.iter().any(|key| {
+                camera.config_key::<gphoto2::widget::RadioWidget>(key)
+                    .wait()
+                    .map(|w| w.choices_iter().any(|c| c.to_lowercase().contains("bulb")))
+                    .unwrap_or(false)
+            });
+
+        CameraCapabilities {
+            live_view: operations.capture_preview(),
+            bulb,
+            video: operations.capture_video() || has_key("movie"),
+            remote_af: has_key("autofocusdrive") || Self::has_remote_release(camera),
+            manual_focus_drive: has_key("manualfocusdrive"),
+            card_format: has_key("formatcard") || has_key("formatstorage"),
+            trigger_capture: operations.trigger_capture(),
+            native_focus_bracket: FOCUS_BRACKET_ENABLE_KEYS.iter().any(|key| has_key(key)),
+            max_fps: Self::read_config_text(camera, BURST_RATE_KEYS)
+                .and_then(|rate| Self::parse_config_number(&rate))
+                .filter(|fps| *fps > 0.0)
+                .or_else(|| burst_rate::lookup(&camera.abilities().model().to_string())),
+        }
+    }
+
+    /// Get the connected camera's capabilities, probing once per connection
+    pub async fn get_capabilities(&self) -> std::result::Result<CameraCapabilities, String> {
+        if let Some(capabilities) = self.capabilities.lock().await.clone() {
+            return Ok(capabilities);
+        }
+
+        let camera = self.connected_camera().await?;
+        let capabilities = tokio::task::spawn_blocking(move || Self::probe_capabilities(&camera))
+            .await
+            .map_err(|e| format!("Task join error: {}", e))?;
+
+        *self.capabilities.lock().await = Some(capabilities.clone());
+        Ok(capabilities)
+    }
+
+    /// Disconnect from current camera
+    pub async fn disconnect_camera(&self, app: AppHandle) -> std::result::Result<(), String> {
+        self.record_connection_event(&app, false, ConnectionReason::User, None).await;
+        *self.camera.lock().await = None;
+        // A user disconnect is not a failure, don't fail over
+        *self.active_port.lock().await = None;
+        *self.cached_params.lock().await = None;
+        app.emit("camera:status", "Disconnected").ok();
+        eprintln!("{} [Camera] Disconnected by user", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"));
+        Ok(())
+    }
+
+    /// Get current camera parameters (internal version with minimal logging)
+    /// Always reads from the camera and refreshes the params cache
+    async fn get_camera_params_internal(&self) -> std::result::Result<CameraParams, String> {
+        let camera = {
+            let camera_guard = self.camera.lock().await;
+            camera_guard
+                .as_ref()
+                .ok_or("No camera connected")?
+                .clone()
+        };
+
+        let liveview_active = self.liveview_active.load(Ordering::Relaxed);
+        let card_only = self.card_only.load(Ordering::Relaxed);
+
+        let params = tokio::task::spawn_blocking(move || {
+            let abilities = camera.abilities();
+            let model = abilities.model().to_string();
+            let port = Self::camera_port(&camera);
+
+            // Get ISO - try multiple key names
+            // A body reviewing images hides capture settings but still answers status reads
+            let iso = match Self::get_radio_value(&camera, ISO_KEYS) {
+                Some(iso) => iso,
+                None if Self::responds_outside_capture(&camera) => Self::wait_for_capture_mode(&camera)
+                    .ok_or_else(|| String::from(TetherError::NotInCaptureMode))?,
+                None => return Err("Failed to get ISO - camera may be disconnected".to_string()),
+            };
+
+            // Get shutter speed
+            let shutter_speed = Self::get_radio_value(&camera, SHUTTER_SPEED_KEYS)
+                .ok_or_else(|| "Failed to get shutter speed - camera may be disconnected")?;
+
+            // Get aperture
+            let aperture = Self::get_radio_value(&camera, APERTURE_KEYS)
+                .ok_or_else(|| "Failed to get aperture - camera may be disconnected")?;
+
+            // Get other parameters (optional)
+            let exposure_compensation = Self::get_radio_value(&camera, EXPOSURE_COMPENSATION_KEYS);
+
+            let shooting_mode = Self::get_radio_value(&camera, SHOOTING_MODE_KEYS);
+            let shooting_mode_locked = Self::is_shooting_mode_locked(&camera);
+
+            let white_balance = Self::get_radio_value(&camera, WHITE_BALANCE_KEYS);
+
+            let aspect_ratio = Self::get_radio_value(&camera, ASPECT_RATIO_KEYS);
+
+            let focus_mode = Self::get_radio_value(&camera, FOCUS_MODE_KEYS);
+
+            let drive_mode = Self::get_radio_value(&camera, DRIVE_MODE_KEYS);
+
+            let metering_mode = Self::get_radio_value(&camera, METERING_MODE_KEYS);
+
+            let shutter_type = Self::read_shutter_type(&camera);
+
+            let capture_destination = Self::get_radio_value(&camera, CAPTURE_TARGET_KEYS)
+                .and_then(|target| CaptureDestination::from_choice(&target, card_only));
+
+            // Try to get battery level
+            let (battery_level, battery_state) = Self::read_battery(&camera);
 
             // Try to get remaining images
-            let images_remaining = camera.config_key::<gphoto2::widget::RangeWidget>("remainingimages")
+            let images_remaining = camera.config_key::<gphoto2::widget::RangeWidget>(IMAGES_REMAINING_KEYS[0])
+                .wait()
+                .ok()
+                .map(|w| w.value() as u32);
+
+            // The meter only tells something useful when exposure is set by hand
+            let exposure_program = shooting_mode.as_deref().and_then(ExposureProgram::from_mode);
+            let exposure_meter = if exposure_program == Some(ExposureProgram::Manual) {
+                Self::read_exposure_meter(&camera)
+            } else {
+                None
+            };
+
+            // Focus confirmation is only reported while live view runs
+            let focus_confirmed = if liveview_active {
+                Self::read_focus_confirmed(&camera)
+            } else {
+                None
+            };
+
+            Ok::<CameraParams, String>(CameraParams {
+                iso,
+                shutter_speed,
+                aperture,
+                exposure_compensation,
+                shooting_mode,
+                shooting_mode_locked,
+                white_balance,
+                focus_mode,
+                drive_mode,
+                metering_mode,
+                aspect_ratio,
+                battery_level,
+                battery_state,
+                images_remaining,
+                focus_confirmed,
+                exposure_meter,
+                exposure_program,
+                shutter_type,
+                capture_destination,
+                model,
+                serial_number: Self::read_serial_number(&camera),
+                connection_speed: Self::usb_connection_speed(&port),
+                port,
+            })
+        })
+        .await
+        .map_err(|e| format!("Task join error: {}", e))??;
+
+        *self.cached_params.lock().await = Some((Instant::now(), params.clone()));
+
+        Ok(params)
+    }
+
+    /// Check whether the camera still answers while its capture settings are gone
+    /// That combination means playback/review mode rather than a dropped connection
+    fn responds_outside_capture(camera: &Camera) -> bool {
+        STATUS_KEYS
+            .iter()
+            .any(|key| camera.config_key::<gphoto2::widget::Widget>(key).wait().is_ok())
+    }
+
+    /// Poll briefly for capture settings to come back after the body leaves playback
+    fn wait_for_capture_mode(camera: &Camera) -> Option<String> {
+        let deadline = Instant::now() + CAPTURE_MODE_WAIT;
+        while Instant::now() < deadline {
+            std::thread::sleep(Duration::from_millis(250));
+            if let Some(iso) = Self::get_radio_value(camera, ISO_KEYS) {
+                return Some(iso);
+            }
+        }
+        None
+    }
+
+    /// Read the battery as a percentage, plus the raw state on bodies with discrete reporting
+    fn read_battery(camera: &Camera) -> (Option<f32>, Option<String>) {
+        for key in BATTERY_LEVEL_KEYS {
+            if let Ok(widget) = camera.config_key::<gphoto2::widget::RangeWidget>(key).wait() {
+                return (Some(widget.value()), None);
+            }
+        }
+
+        match Self::read_config_text(camera, BATTERY_LEVEL_KEYS) {
+            Some(state) => (Self::battery_state_percent(&state), Some(state)),
+            None => (None, None),
+        }
+    }
+
+    /// Map a discrete battery state to an approximate percentage ("75%" is taken as is)
+    fn battery_state_percent(state: &str) -> Option<f32> {
+        let lower = state.trim().to_lowercase();
+        if let Some(percent) = lower.strip_suffix('%').and_then(|p| p.trim().parse::<f32>().ok()) {
+            return Some(percent);
+        }
+        if lower.contains("full") || lower == "high" {
+            Some(100.0)
+        } else if lower.contains("half") || lower == "normal" || lower == "medium" {
+            Some(50.0)
+        } else if lower.contains("empty") || lower.contains("exhausted") {
+            Some(0.0)
+        } else if lower.contains("low") {
+            Some(20.0)
+        } else {
+            lower.parse::<f32>().ok()
+        }
+    }
+
+    /// Get current camera parameters (public wrapper)
+    /// Serves a recent cached read unless `force` is set, e.g. right after a dial change on the body
+    pub async fn get_camera_params(&self, force: bool) -> std::result::Result<CameraParams, String> {
+        if !force {
+            if let Some((read_at, params)) = self.cached_params.lock().await.as_ref() {
+                if read_at.elapsed() < PARAMS_CACHE_TTL {
+                    return Ok(params.clone());
+                }
+            }
+        }
+        self.get_camera_params_internal().await
+    }
+
+    /// Cheap liveness check: a single config read instead of the full params sweep
+    /// Returns the raw gphoto2 error so the caller can classify disconnects
+    async fn probe_camera(&self) -> std::result::Result<(), String> {
+        let camera = self.connected_camera().await?;
+
+        tokio::task::spawn_blocking(move || {
+            let mut last_error = String::from("No ISO config key found");
+            for key in ISO_KEYS {
+                match camera.config_key::<gphoto2::widget::RadioWidget>(key).wait() {
+                    Ok(_) => return Ok(()),
+                    Err(e) => last_error = e.to_string(),
+                }
+            }
+            // Still connected, just in playback mode - not a reason to tear down
+            if Self::responds_outside_capture(&camera) {
+                return Ok(());
+            }
+            Err(last_error)
+        })
+        .await
+        .map_err(|e| format!("Task join error: {}", e))?
+    }
+
+    /// Grab one live view frame (usually JPEG) into memory
+    pub async fn capture_preview(&self) -> std::result::Result<Vec<u8>, String> {
+        let context = Self::new_context()?;
+        self.read_preview_frame(&context).await
+    }
+
+    /// Grab one live view frame with a context the caller keeps for the whole stream
+    async fn read_preview_frame(&self, context: &Context) -> std::result::Result<Vec<u8>, String> {
+        let camera = self.connected_camera().await?;
+        let context = context.clone();
+        tokio::task::spawn_blocking(move || {
+            let frame = camera.capture_preview()
                 .wait()
-                .ok()
-                .map(|w| w.value() as u32);
+                .map_err(|e| format!("Failed to capture preview: {}", e))?
+                .get_data(&context)
+                .wait()
+                .map_err(|e| format!("Failed to read preview: {}", e))?;
+            Ok::<Vec<u8>, String>(frame.to_vec())
+        })
+        .await
+        .map_err(|e| format!("Task join error: {}", e))?
+    }
+
+    /// Stream live view frames on `camera:preview` until stop_liveview
+    /// Replaces a live view that is already running. While streaming, the monitor stops polling
+    /// params (the loop refreshes them itself) and shortens its event waits between frames.
+    /// Fails with a busy error while a capture or other exclusive operation runs.
+    pub async fn start_liveview(self: Arc<Self>, app: AppHandle, fps: Option<f32>) -> std::result::Result<(), String> {
+        let running = *self.active_operation.lock().unwrap();
+        if let Some(running) = running {
+            return Err(TetherError::Busy(running.to_string()).into());
+        }
+        self.connected_camera().await?;
+        // One context for the whole stream instead of one per frame
+        let context = Self::new_context()?;
+        let fps = fps.unwrap_or(LIVEVIEW_DEFAULT_FPS).clamp(LIVEVIEW_MIN_FPS, LIVEVIEW_MAX_FPS);
+
+        let stop = Arc::new(AtomicBool::new(false));
+        if let Some(previous) = self.liveview_stop.lock().unwrap().replace(stop.clone()) {
+            previous.store(true, Ordering::Relaxed);
+        }
+        self.liveview_active.store(true, Ordering::Relaxed);
+
+        let this = self.clone();
+        let handle = tokio::spawn(async move {
+            this.run_liveview(app, stop, fps, context).await;
+        });
+        self.track_task(handle);
+
+        eprintln!("{} [Camera] Live view started at {} fps", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), fps);
+        Ok(())
+    }
+
+    /// End the running live view, if any
+    pub fn stop_liveview(&self) {
+        if let Some(stop) = self.liveview_stop.lock().unwrap().take() {
+            stop.store(true, Ordering::Relaxed);
+            self.liveview_active.store(false, Ordering::Relaxed);
+            eprintln!("{} [Camera] Live view stopped", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"));
+        }
+    }
+
+    async fn run_liveview(self: Arc<Self>, app: AppHandle, stop: Arc<AtomicBool>, fps: f32, context: Context) {
+        let mut interval = tokio::time::interval(Duration::from_secs_f32(1.0 / fps));
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+        let mut frame_index: u64 = 0;
+        loop {
+            interval.tick().await;
+
+            if stop.load(Ordering::Relaxed) || self.shutdown.load(Ordering::Relaxed) {
+                break;
+            }
+            if self.camera.lock().await.is_none() {
+                break;
+            }
+            let frame = {
+                let _frame = self.liveview_frame.lock().await;
+                // A capture owns the camera; pick the stream back up once it's done
+                if self.active_operation.lock().unwrap().is_some() {
+                    continue;
+                }
+                let _io = self.camera_io_permit().await;
+                self.read_preview_frame(&context).await
+            };
+            match frame {
+                Ok(frame) => {
+                    app.emit("camera:preview", serde_json::json!({
+                        "frame": frame_index,
+                        "data": format!("data:image/jpeg;base64,{}", general_purpose::STANDARD.encode(&frame)),
+                    })).ok();
+                    self.emit_liveview_params(&app, frame_index).await;
+                    self.emit_focus_peaking(&app, frame, frame_index).await;
+                    frame_index += 1;
+                }
+                Err(e) => {
+                    let error_msg = e.to_lowercase();
+                    if Self::is_disconnect_error(&error_msg) {
+                        eprintln!("{} [Camera] Disconnected during live view: {}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), e);
+                        self.record_connection_event(&app, false, ConnectionReason::from_error(&error_msg), Some(e)).await;
+                        *self.camera.lock().await = None;
+                        let _ = app.emit("camera:status", "Disconnected");
+                        break;
+                    }
+                    eprintln!("{} [Camera] Live view frame failed: {}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), e);
+                }
+            }
+        }
+
+        // A newer live view may have taken over; leave its state alone
+        let mut current = self.liveview_stop.lock().unwrap();
+        if current.as_ref().map_or(true, |token| Arc::ptr_eq(token, &stop)) {
+            *current = None;
+            self.liveview_active.store(false, Ordering::Relaxed);
+        }
+    }
+
+    /// Refresh params from inside the live view loop, throttled to every Nth frame
+    /// Emits `camera:params` so exposure readouts track dial changes without a second poll
+    async fn emit_liveview_params(&self, app: &AppHandle, frame_index: u64) {
+        if frame_index % LIVEVIEW_PARAMS_EVERY_N_FRAMES != 0 {
+            return;
+        }
+        if let Ok(params) = self.get_camera_params_internal().await {
+            app.emit("camera:params", &params).ok();
+        }
+    }
+
+    /// Compute and emit a focus peaking overlay from inside the live view loop
+    /// Runs off the async runtime and only on every Nth frame, so the feed keeps its frame rate
+    async fn emit_focus_peaking(&self, app: &AppHandle, frame: Vec<u8>, frame_index: u64) {
+        if frame_index % LIVEVIEW_PEAKING_EVERY_N_FRAMES != 0 {
+            return;
+        }
+        if !self.options.lock().await.focus_peaking {
+            return;
+        }
+
+        let overlay = tokio::task::spawn_blocking(move || focus_peaking::compute_overlay(&frame, frame_index)).await;
+        match overlay {
+            Ok(Ok(overlay)) => {
+                app.emit("camera:liveview_peaking", &overlay).ok();
+            }
+            Ok(Err(e)) => {
+                eprintln!("{} [Camera] Focus peaking failed: {}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), e);
+            }
+            Err(e) => {
+                eprintln!("{} [Camera] Focus peaking task join error: {}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), e);
+            }
+        }
+    }
+
+    /// Map each logical param to the config key that resolved on this camera (or None)
+    /// Useful for seeing why a field fails to read on a new body and which alias to add
+    pub async fn get_param_key_map(&self) -> std::result::Result<std::collections::HashMap<String, Option<String>>, String> {
+        let camera = self.connected_camera().await?;
+
+        tokio::task::spawn_blocking(move || {
+            PARAM_KEY_ALIASES
+                .iter()
+                .map(|(field, keys)| {
+                    // Match the widget type the params read expects, not just key existence
+                    let is_range = *field == "imagesRemaining";
+                    let resolved = keys
+                        .iter()
+                        .find(|key| {
+                            if *field == "batteryLevel" {
+                                // Discrete-state bodies report the battery as a radio or text
+                                Self::read_config_text(&camera, &[**key]).is_some()
+                            } else if is_range {
+                                camera.config_key::<gphoto2::widget::RangeWidget>(key).wait().is_ok()
+                            } else {
+                                camera.config_key::<gphoto2::widget::RadioWidget>(key).wait().is_ok()
+                            }
+                        })
+                        .map(|key| key.to_string());
+                    (field.to_string(), resolved)
+                })
+                .collect()
+        })
+        .await
+        .map_err(|e| format!("Task join error: {}", e))
+    }
+
+    /// Check that iso, shutter speed and aperture resolved through the alias lists
+    /// For each that didn't, the config tree is searched for keys with a matching label, so a
+    /// new body's key can be added to the aliases.
+    pub async fn verify_param_aliases(&self) -> std::result::Result<AliasReport, String> {
+        let key_map = self.get_param_key_map().await?;
+        let unresolved: Vec<String> = REQUIRED_PARAM_LABELS
+            .iter()
+            .filter(|(field, _)| key_map.get(*field).map_or(true, |key| key.is_none()))
+            .map(|(field, _)| field.to_string())
+            .collect();
+
+        let camera = self.connected_camera().await?;
+        tokio::task::spawn_blocking(move || {
+            let model = camera.abilities().model().to_string();
+            let candidates = if unresolved.is_empty() {
+                std::collections::HashMap::new()
+            } else {
+                let labelled = Self::list_config_labels(&camera);
+                REQUIRED_PARAM_LABELS
+                    .iter()
+                    .filter(|(field, _)| unresolved.iter().any(|u| u == field))
+                    .map(|(field, terms)| {
+                        let keys = labelled
+                            .iter()
+                            .filter(|(_, label)| terms.iter().any(|term| label.contains(term)))
+                            .map(|(key, _)| key.clone())
+                            .collect();
+                        (field.to_string(), keys)
+                    })
+                    .collect()
+            };
+            AliasReport { model, unresolved, candidates }
+        })
+        .await
+        .map_err(|e| format!("Task join error: {}", e))
+    }
+
+    /// Every non-group config key with its lowercased label
+    fn list_config_labels(camera: &Camera) -> Vec<(String, String)> {
+        let Ok(root) = camera.config().wait() else {
+            return Vec::new();
+        };
+        let mut labelled = Vec::new();
+        let mut pending: Vec<gphoto2::widget::Widget> = root.children_iter().collect();
+        while let Some(widget) = pending.pop() {
+            match widget {
+                gphoto2::widget::Widget::Group(group) => pending.extend(group.children_iter()),
+                widget => labelled.push((widget.name().to_string(), widget.label().to_lowercase())),
+            }
+        }
+        labelled
+    }
+
+    /// Run verify_param_aliases after connecting; logs candidates and emits `camera:alias_warning`
+    async fn warn_unresolved_aliases(&self, app: &AppHandle) {
+        match self.verify_param_aliases().await {
+            Ok(report) if !report.unresolved.is_empty() => {
+                for field in &report.unresolved {
+                    let candidates = report.candidates.get(field).cloned().unwrap_or_default();
+                    eprintln!("{} [Camera] {} has no alias for '{}', candidate keys: {:?}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), report.model, field, candidates);
+                }
+                app.emit("camera:alias_warning", &report).ok();
+            }
+            Ok(_) => {}
+            Err(e) => {
+                eprintln!("{} [Camera] Failed to verify param aliases: {}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), e);
+            }
+        }
+    }
+
+    /// Get available choices for a configuration parameter
+    pub async fn get_config_choices(&self, config_key: &str) -> std::result::Result<Vec<String>, String> {
+        let camera = {
+            let camera_guard = self.camera.lock().await;
+            camera_guard
+                .as_ref()
+                .ok_or("No camera connected")?
+                .clone()
+        };
+
+        let key = config_key.to_string();
+        tokio::task::spawn_blocking(move || {
+            let radio_error = match camera.config_key::<gphoto2::widget::RadioWidget>(&key).wait() {
+                Ok(widget) => {
+                    let choices: Vec<String> = widget.choices_iter().map(|c| c.to_string()).collect();
+                    return Ok(choices);
+                }
+                Err(e) => e,
+            };
+
+            // Some bodies expose numeric settings (e.g. exposure compensation) as a range
+            if let Ok(widget) = camera.config_key::<gphoto2::widget::RangeWidget>(&key).wait() {
+                let (range, step) = widget.range_and_step();
+                return Ok(Self::range_choices(*range.start(), *range.end(), step));
+            }
+
+            Err(format!("Failed to get config '{}': {}", key, radio_error))
+        })
+        .await
+        .map_err(|e| format!("Task join error: {}", e))?
+    }
+
+    /// Generate the sorted values of a range widget from its bounds and step
+    fn range_choices(min: f32, max: f32, step: f32) -> Vec<String> {
+        if step <= 0.0 || max < min {
+            return vec![Self::format_range_value(min, step)];
+        }
+
+        // Step by index to avoid accumulating float error across many steps
+        let count = ((max - min) / step).round() as usize;
+        (0..=count)
+            .map(|i| Self::format_range_value(min + i as f32 * step, step))
+            .collect()
+    }
+
+    /// Format a range value with as many decimals as the step needs
+    fn format_range_value(value: f32, step: f32) -> String {
+        if step.fract() == 0.0 {
+            format!("{}", value.round() as i64)
+        } else {
+            let formatted = format!("{:.2}", value);
+            formatted.trim_end_matches('0').trim_end_matches('.').to_string()
+        }
+    }
+
+    /// Get ISO choices with numeric values, including expanded L/H settings
+    pub async fn get_iso_choices(&self) -> std::result::Result<Vec<IsoValue>, String> {
+        let choices = self.get_config_choices("iso").await?;
+        Ok(iso::parse_iso_choices(&choices))
+    }
+
+    /// Read the camera's self-timer from the first widget that offers timer delays
+    fn read_self_timer(camera: &Camera) -> Option<SelfTimer> {
+        for key in SELF_TIMER_KEYS {
+            let Ok(widget) = camera.config_key::<gphoto2::widget::RadioWidget>(key).wait() else {
+                continue;
+            };
+            let labels: Vec<String> = widget.choices_iter().map(|c| c.to_string()).collect();
+            let choices = self_timer::parse_timer_choices(&labels, key.starts_with("selftimer"));
+            if choices.is_empty() {
+                continue;
+            }
+
+            // On drive mode widgets any non-timer mode (e.g. continuous) means the timer is off
+            let current = widget.choice().to_string();
+            let seconds = choices
+                .iter()
+                .find(|choice| choice.label == current)
+                .map_or(Some(0), |choice| choice.seconds);
+
+            return Some(SelfTimer { key: key.to_string(), seconds, choices });
+        }
+        None
+    }
+
+    /// Get the camera's self-timer delay and available choices
+    pub async fn get_self_timer(&self) -> std::result::Result<SelfTimer, String> {
+        let camera = self.connected_camera().await?;
+        tokio::task::spawn_blocking(move || Self::read_self_timer(&camera))
+            .await
+            .map_err(|e| format!("Task join error: {}", e))?
+            .ok_or_else(|| "This camera does not expose a self-timer setting".to_string())
+    }
+
+    /// Set the camera's self-timer delay in seconds (0 turns it off)
+    pub async fn set_self_timer(&self, seconds: u32) -> std::result::Result<(), String> {
+        let timer = self.get_self_timer().await?;
+        let choice = timer
+            .choices
+            .iter()
+            .find(|choice| choice.seconds == Some(seconds))
+            .ok_or_else(|| {
+                let available: Vec<&str> = timer.choices.iter().map(|c| c.label.as_str()).collect();
+                format!("Self-timer delay of {}s not supported (available: {})", seconds, available.join(", "))
+            })?;
+
+        self.apply_config_value(&timer.key, &choice.label).await
+    }
+
+    /// Set a configuration parameter value
+    /// With `config_debounce_ms` set, rapid calls for the same key (e.g. a dragged slider) coalesce:
+    /// each call waits out the window and only the last value is sent to the camera.
+    pub async fn set_config_value(&self, config_key: &str, value: &str) -> std::result::Result<(), String> {
+        let debounce_ms = self.options.lock().await.config_debounce_ms;
+        if debounce_ms > 0 {
+            let generation = {
+                let mut pending = self.pending_config.lock().unwrap();
+                let generation = pending.entry(config_key.to_string()).or_insert(0);
+                *generation += 1;
+                *generation
+            };
+
+            tokio::time::sleep(Duration::from_millis(debounce_ms)).await;
+
+            // A newer value for this key arrived during the window; it will be applied instead
+            let latest = self.pending_config.lock().unwrap().get(config_key).copied();
+            if latest != Some(generation) {
+                return Ok(());
+            }
+        }
+
+        self.apply_config_value(config_key, value).await
+    }
+
+    /// Send a configuration value to the camera immediately
+    async fn apply_config_value(&self, config_key: &str, value: &str) -> std::result::Result<(), String> {
+        let camera = {
+            let camera_guard = self.camera.lock().await;
+            camera_guard
+                .as_ref()
+                .ok_or("No camera connected")?
+                .clone()
+        };
+
+        let key = config_key.to_string();
+        let value = value.to_string();
+        tokio::task::spawn_blocking(move || {
+            if SHOOTING_MODE_KEYS.contains(&key.as_str()) && Self::is_shooting_mode_locked(&camera) {
+                return Err("Shooting mode is locked by the mode dial on the body".to_string());
+            }
+            if let Err(radio_error) = Self::set_radio_value(&camera, &key, &value) {
+                // Fall back to range widgets, which take the numeric value
+                let widget = match camera.config_key::<gphoto2::widget::RangeWidget>(&key).wait() {
+                    Ok(widget) => widget,
+                    Err(_) => return Err(radio_error),
+                };
+                if widget.readonly() {
+                    return Err(format!("Config '{}' is readonly", key));
+                }
+                let number = value.trim().trim_start_matches('+').parse::<f32>()
+                    .map_err(|_| format!("Value '{}' for '{}' is not a number", value, key))?;
+                widget.set_value(number)
+                    .map_err(|e| format!("Failed to set value '{}' for '{}': {}", value, key, e))?;
+                camera.set_config(&widget)
+                    .wait()
+                    .map_err(|e| format!("Failed to apply config '{}': {}", key, e))?;
+            }
+
+            // Small delay to let camera process the change
+            std::thread::sleep(std::time::Duration::from_millis(100));
+
+            Ok::<(), String>(())
+        })
+        .await
+        .map_err(|e| format!("Task join error: {}", e))??;
+
+        // Cached params no longer reflect the camera
+        *self.cached_params.lock().await = None;
+
+        Ok(())
+    }
+
+    /// Switch the image format for a capture sequence
+    /// Returns the key and original value so the sequence can restore it afterward, even on failure.
+    async fn apply_image_format(&self, image_format: Option<&str>) -> std::result::Result<Option<(String, String)>, String> {
+        let Some(image_format) = image_format else {
+            return Ok(None);
+        };
+        let camera = self.connected_camera().await?;
+        let image_format = image_format.to_string();
+
+        tokio::task::spawn_blocking(move || {
+            for key in IMAGE_FORMAT_KEYS {
+                if let Ok(widget) = camera.config_key::<gphoto2::widget::RadioWidget>(key).wait() {
+                    let original = widget.choice().to_string();
+                    if original != image_format {
+                        Self::set_radio_value(&camera, key, &image_format)?;
+                    }
+                    return Ok(Some((key.to_string(), original)));
+                }
+            }
+            Err("Camera does not expose an image format setting".to_string())
+        })
+        .await
+        .map_err(|e| format!("Task join error: {}", e))?
+    }
+
+    /// Restore an image format changed by apply_image_format
+    async fn restore_image_format(&self, original: Option<(String, String)>) {
+        let Some((key, value)) = original else {
+            return;
+        };
+        if let Err(e) = self.apply_config_value(&key, &value).await {
+            eprintln!("{} [Camera] Failed to restore image format '{}': {}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), value, e);
+        }
+    }
+
+    /// Apply all settings of a preset, or none of them
+    /// Original values are recorded first; if any setting fails, the ones already applied
+    /// are restored in reverse order and the error names the setting that failed.
+    pub async fn apply_preset_atomic(&self, preset: CameraPreset) -> std::result::Result<(), String> {
+        let camera = self.connected_camera().await?;
+
+        tokio::task::spawn_blocking(move || {
+            // Record originals up front so a missing key fails before anything changes
+            let mut originals = Vec::with_capacity(preset.settings.len());
+            for setting in &preset.settings {
+                let widget = camera.config_key::<gphoto2::widget::RadioWidget>(&setting.key)
+                    .wait()
+                    .map_err(|e| format!("Failed to read '{}' before applying preset: {}", setting.key, e))?;
+                originals.push(widget.choice().to_string());
+            }
+
+            for (index, setting) in preset.settings.iter().enumerate() {
+                if let Err(e) = Self::set_radio_value(&camera, &setting.key, &setting.value) {
+                    // Roll back everything applied so far
+                    for (applied, original) in preset.settings[..index].iter().zip(&originals).rev() {
+                        if let Err(restore_err) = Self::set_radio_value(&camera, &applied.key, original) {
+                            eprintln!("{} [Camera] Failed to restore '{}': {}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), applied.key, restore_err);
+                        }
+                    }
+                    return Err(format!(
+                        "Preset '{}' failed at '{}' = '{}', previous settings restored: {}",
+                        preset.name, setting.key, setting.value, e
+                    ));
+                }
+                std::thread::sleep(Duration::from_millis(100));
+            }
+
+            Ok(())
+        })
+        .await
+        .map_err(|e| format!("Task join error: {}", e))??;
+
+        *self.cached_params.lock().await = None;
+        Ok(())
+    }
 
-            Ok::<CameraParams, String>(CameraParams {
-                iso,
-                shutter_speed,
-                aperture,
-                exposure_compensation,
-                shooting_mode,
-                white_balance,
-                focus_mode,
-                drive_mode,
-                metering_mode,
-                battery_level,
-                images_remaining,
-                model,
-                port,
-            })
+    /// Reset the body's settings through its remote factory reset
+    /// Requires `confirm`, since the reset also clears custom functions and menus. Bodies without
+    /// a remote reset get an unsupported error pointing at apply_default_settings instead.
+    pub async fn reset_camera_settings(&self, confirm: bool) -> std::result::Result<(), String> {
+        if !confirm {
+            return Err("Resetting camera settings requires confirmation".to_string());
+        }
+        let camera = self.connected_camera().await?;
+        tokio::task::spawn_blocking(move || {
+            let setting = Self::read_config_setting(&camera, FACTORY_RESET_KEYS)
+                .ok_or_else(|| "Camera does not support a remote settings reset; apply the built-in defaults instead".to_string())?;
+            let value = setting.choices
+                .iter()
+                .find(|choice| {
+                    let lower = choice.to_lowercase();
+                    lower == "on" || lower.contains("reset") || lower.contains("execute")
+                })
+                .ok_or_else(|| format!("No reset action among '{}' choices: {}", setting.key, setting.choices.join(", ")))?;
+            eprintln!("{} [Camera] Resetting camera settings via '{}'", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), setting.key);
+            Self::write_config_setting(&camera, &setting.key, value)
         })
         .await
         .map_err(|e| format!("Task join error: {}", e))??;
 
-        Ok(params)
+        *self.cached_params.lock().await = None;
+        Ok(())
     }
 
-    /// Get current camera parameters (public wrapper)
-    pub async fn get_camera_params(&self) -> std::result::Result<CameraParams, String> {
-        self.get_camera_params_internal().await
+    /// Apply the built-in defaults (auto ISO and WB, no compensation, single shot, ...) as a preset
+    /// Only settings the body exposes with a matching choice are included; returns what was applied.
+    pub async fn apply_default_settings(&self) -> std::result::Result<CameraPreset, String> {
+        let camera = self.connected_camera().await?;
+        let preset = tokio::task::spawn_blocking(move || {
+            let settings = SANE_DEFAULTS
+                .iter()
+                .filter_map(|(keys, preferred)| {
+                    let setting = Self::read_config_setting(&camera, keys)?;
+                    let value = preferred.iter().find_map(|wanted| {
+                        setting.choices.iter().find(|choice| choice.eq_ignore_ascii_case(wanted))
+                    })?;
+                    Some(PresetSetting { key: setting.key, value: value.clone() })
+                })
+                .collect();
+            CameraPreset { name: "Defaults".to_string(), settings }
+        })
+        .await
+        .map_err(|e| format!("Task join error: {}", e))?;
+
+        if preset.settings.is_empty() {
+            return Err("Camera exposes none of the default settings".to_string());
+        }
+        self.apply_preset_atomic(preset.clone()).await?;
+        Ok(preset)
     }
 
-    /// Get available choices for a configuration parameter
-    pub async fn get_config_choices(&self, config_key: &str) -> std::result::Result<Vec<String>, String> {
+    /// Capture a photo and download it directly to target folder
+    /// With `skip_af` the shot fires without re-focusing, keeping a lock taken by lock_focus.
+    /// Only bodies with staged release (Canon) can skip AF; others capture normally.
+    /// With `autofocus` AF runs first and counts against the same capture timeout.
+    pub async fn capture_and_download(&self, app: AppHandle, target_folder: Option<String>, capture_options: CaptureOptions) -> std::result::Result<CaptureResult, String> {
+        let _operation = self.begin_operation("capture").await?;
+        self.capture_frame(app, target_folder, capture_options).await
+    }
+
+    /// capture_and_download for sequences that already hold an operation across all their frames
+    async fn capture_frame(&self, app: AppHandle, target_folder: Option<String>, capture_options: CaptureOptions) -> std::result::Result<CaptureResult, String> {
+        let skip_af = capture_options.skip_af;
+        if skip_af && capture_options.autofocus {
+            return Err("autofocus and skipAf can't be combined".to_string());
+        }
+        if skip_af && !self.focus_locked.load(Ordering::Relaxed) {
+            return Err("skipAf needs focus held by lock_focus first".to_string());
+        }
+        let started = Instant::now();
+
         let camera = {
             let camera_guard = self.camera.lock().await;
             camera_guard
@@ -300,178 +4132,1042 @@ impl CameraService {
                 .clone()
         };
 
-        let key = config_key.to_string();
+        // Use target folder if provided, otherwise use default capture dir
+        let capture_dir = if let Some(ref folder) = target_folder {
+            // Store this as the current download folder for camera button captures
+            *self.current_download_folder.lock().await = Some(folder.clone());
+            std::path::PathBuf::from(folder)
+        } else {
+            self.capture_dir.clone()
+        };
+        let (capture_dir, session_id) = self.resolve_capture_dir(capture_dir).await;
+        if capture_options.autofocus {
+            let af_camera = camera.clone();
+            tokio::task::spawn_blocking(move || Self::autofocus_blocking(&af_camera, AUTOFOCUS_TIMEOUT))
+                .await
+                .map_err(|e| format!("Task join error: {}", e))?
+                .map_err(|e| format!("Capture cancelled: {}", e))?;
+        }
+        if self.card_only.load(Ordering::Relaxed) {
+            return self.capture_to_card(&app, camera, skip_af, session_id).await;
+        }
+        let options = self.options.lock().await.clone();
+        let session_id_for_dir = session_id.clone();
+        let known_camera_files = self.known_camera_files.clone();
+        let output_name = capture_options.output_name.clone();
+        let calibration = capture_options.calibration;
+        let pair_dir = capture_dir.clone();
+        let pair_options = options.clone();
+
+        let io_permit = self.camera_io_permit().await;
+
+        // Add timeout to prevent blocking; autofocus already used part of the budget
+        let capture_result = tokio::time::timeout(
+            CAPTURE_TIMEOUT.saturating_sub(started.elapsed()),
+            tokio::task::spawn_blocking(move || {
+                eprintln!("{} [Camera] Capturing photo...", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"));
+                let image_path = Self::fire_shutter(&camera, skip_af, &options)?;
+                known_camera_files.lock().unwrap().insert(format!("{}/{}", image_path.folder(), image_path.name()));
+
+                // Get file info
+                let original_name = image_path.name();
+                let ext = Self::extract_file_extension(&original_name);
+
+                // Generate filename from timestamp or camera sequence
+                let capture_dir = Self::dir_for_file_type(&capture_dir, &ext, &options, session_id_for_dir.as_deref());
+                let capture_dir = match calibration {
+                    Some(kind) => capture_dir.join("calibration").join(kind.folder_name()),
+                    None => capture_dir,
+                };
+                let file_path = Self::capture_file_path(&capture_dir, &original_name, &ext, &options, output_name.as_deref())?;
+
+                // Ensure capture directory exists
+                std::fs::create_dir_all(&capture_dir)
+                    .map_err(|e| format!("Failed to create capture directory: {}", e))?;
+
+                // Download the file
+                eprintln!("{} [Camera] Downloading file...", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"));
+                let download_started = Instant::now();
+                let camera_size = Self::download_verified(&camera, &image_path.folder(), &image_path.name(), &file_path, options.verify_downloads)?;
+                let download_time = download_started.elapsed();
+                eprintln!("{} [Camera] Downloaded to: {}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), file_path.display());
+                let checksum = if options.verify_downloads {
+                    Self::file_checksum(&file_path.to_string_lossy())
+                } else {
+                    None
+                };
+
+                // Get dimensions - use cached value or quick check, fall back to default
+                // For RAW files, use default dimensions immediately to avoid blocking
+                let is_raw = Self::is_raw_file(&file_path.to_string_lossy());
+                let camera_model = camera.abilities().model().to_string();
+
+                // For RAW files, use the model's sensor size to avoid blocking
+                // For JPEG, try to get actual dimensions quickly
+                let dimensions = if is_raw {
+                    // Use model table for RAW - avoids slow rawler parsing
+                    eprintln!("{} [Camera] Using model fallback dimensions for RAW file", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"));
+                    sensor_dimensions::fallback_dimensions(&camera_model)
+                } else {
+                    // For JPEG, quick image crate check
+                    Self::get_image_dimensions(&file_path)
+                        .unwrap_or_else(|| sensor_dimensions::fallback_dimensions(&camera_model))
+                };
+
+                Ok::<(PathBuf, u32, u32, Option<u64>, Option<String>, Duration), String>((file_path, dimensions.0, dimensions.1, camera_size, checksum, download_time))
+            })
+        ).await
+        .map_err(|e| format!("Task join error: {}", e))?;  // Handle JoinError
+
+        // Handle both timeout and capture errors
+        let (file_path, width, height, camera_file_size, checksum, download_time) = match capture_result {
+            Ok(inner_result) => inner_result.map_err(|e| {
+                self.record_capture_error(&app, &e);
+                format!("Capture error: {}", e)
+            })?,
+            Err(_) => return Err(format!("Capture timeout after {} seconds. Camera may be disconnected or busy.", CAPTURE_TIMEOUT.as_secs())),
+        };
+        drop(io_permit);
+        self.consecutive_busy.store(0, Ordering::Relaxed);
+
+        let file_path = file_path.to_string_lossy().to_string();
+        let (raw_file_size, jpg_file_size) = Self::paired_file_sizes(&file_path, &pair_dir, &pair_options, session_id.as_deref());
+        self.report_transfer_stats(&app, Self::local_file_size(&file_path), download_time);
+        self.write_capture_sidecar(&file_path, capture_options.rating).await;
+        Self::write_capture_rating(&file_path, capture_options.rating, capture_options.color_label.as_deref());
+        if let Some(kind) = calibration {
+            Self::add_capture_tag(&file_path, kind.tag());
+        }
+        let orientation = self.capture_orientation(&file_path).await;
+        let previous_capture = self.record_capture(&file_path).await;
+        let result = CaptureResult {
+            format: CaptureFormat::from_paths(&[&file_path]),
+            previous_capture,
+            session_id,
+            orientation,
+            file_size: Self::local_file_size(&file_path),
+            raw_file_size,
+            jpg_file_size,
+            camera_file_size,
+            checksum,
+            capture_id: None,
+            sequence_id: None,
+            aspect_ratio: self.cached_aspect_ratio().await,
+            calibration,
+            af_point: self.get_af_point().await.ok().flatten(),
+            file_path,
+            raw_path: None,
+            jpg_path: None,
+            preview_path: None,
+            width,
+            height,
+        };
+
+        // Emit capture complete event
+        self.emit_captured(&app, &result).await;
+        if capture_options.open_in_editor {
+            app.emit("camera:open_in_editor", &result.file_path).ok();
+        }
+
+        Ok(result)
+    }
+
+    /// Emit `camera:captured` and remember the result for get_recent_captures
+    async fn emit_captured(&self, app: &AppHandle, result: &CaptureResult) {
+        let (capacity, sound, beep, upload_endpoint) = {
+            let options = self.options.lock().await;
+            (options.recent_captures_size, options.play_sound_on_capture.clone(), options.beep_on_capture, options.upload_endpoint.clone())
+        };
+        if let Some(sound) = sound {
+            Self::play_capture_sound(&sound);
+        }
+        if beep {
+            Self::play_system_beep();
+        }
+        {
+            let mut recent = self.recent_captures.lock().unwrap();
+            recent.push_back(result.clone());
+            while recent.len() > capacity {
+                recent.pop_front();
+            }
+        }
+        app.emit("camera:captured", result).ok();
+
+        if let Some(settings) = self.remote_preview.lock().await.clone() {
+            Self::spawn_remote_preview(app, result, settings);
+        }
+        if let Some(endpoint) = upload_endpoint {
+            self.queue_upload(app, endpoint, result.clone());
+        }
+    }
+
+    /// Hand a capture to the upload worker, starting it on first use or after it stopped
+    fn queue_upload(&self, app: &AppHandle, endpoint: String, result: CaptureResult) {
+        let mut queue = self.upload_queue.lock().unwrap();
+        let item = match queue.as_ref() {
+            Some(sender) => match sender.send((endpoint, result)) {
+                Ok(()) => return,
+                Err(tokio::sync::mpsc::error::SendError(item)) => item,
+            },
+            None => (endpoint, result),
+        };
+
+        let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel::<(String, CaptureResult)>();
+        sender.send(item).ok();
+        *queue = Some(sender);
+
+        let app = app.clone();
+        tokio::spawn(async move {
+            let client = reqwest::Client::new();
+            while let Some((endpoint, result)) = receiver.recv().await {
+                let outcome = Self::upload_capture(&client, &endpoint, &result).await;
+                if let Err(e) = &outcome {
+                    eprintln!("{} [Camera] Upload of {} to {} failed: {}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), result.file_path, endpoint, e);
+                }
+                app.emit("camera:upload_result", serde_json::json!({
+                    "filePath": result.file_path,
+                    "endpoint": endpoint,
+                    "success": outcome.is_ok(),
+                    "status": outcome.as_ref().ok(),
+                    "error": outcome.as_ref().err(),
+                    "pending": receiver.len(),
+                })).ok();
+            }
+        });
+    }
+
+    /// POST one capture as multipart: the file under `file`, its CaptureResult as JSON under `metadata`
+    async fn upload_capture(client: &reqwest::Client, endpoint: &str, result: &CaptureResult) -> std::result::Result<u16, String> {
+        let bytes = tokio::fs::read(&result.file_path)
+            .await
+            .map_err(|e| format!("Failed to read {}: {}", result.file_path, e))?;
+        let file_name = std::path::Path::new(&result.file_path)
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| "capture".to_string());
+        let metadata = serde_json::to_string(result).map_err(|e| format!("Failed to serialize capture metadata: {}", e))?;
+
+        let form = reqwest::multipart::Form::new()
+            .part("file", reqwest::multipart::Part::bytes(bytes).file_name(file_name))
+            .text("metadata", metadata);
+        let response = client
+            .post(endpoint)
+            .multipart(form)
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(format!("Endpoint responded with status {}", status));
+        }
+        Ok(status.as_u16())
+    }
+
+    /// Encode a bandwidth-limited preview of a capture in the background and emit it on
+    /// `camera:remote_preview`; the capture on disk is left untouched
+    fn spawn_remote_preview(app: &AppHandle, result: &CaptureResult, settings: RemotePreviewSettings) {
+        let app = app.clone();
+        let file_path = result.file_path.clone();
+        let source = result.jpg_path.clone().unwrap_or_else(|| file_path.clone());
         tokio::task::spawn_blocking(move || {
-            let widget = camera.config_key::<gphoto2::widget::RadioWidget>(&key)
-                .wait()
-                .map_err(|e| format!("Failed to get config '{}': {}", key, e))?;
+            let preview = get_cached_or_generate_thumbnail_image(&source, &app, None)
+                .map_err(|e| e.to_string())
+                .and_then(|image| remote_preview::encode_within_budget(&image, &settings, &file_path));
+            match preview {
+                Ok(preview) => {
+                    app.emit("camera:remote_preview", &preview).ok();
+                }
+                Err(e) => {
+                    eprintln!("{} [Camera] Remote preview failed for {}: {}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), file_path, e);
+                }
+            }
+        });
+    }
+
+    /// Turn remote previews on (format and byte budget) or off for the current session
+    /// Cleared when the session ends.
+    pub async fn set_remote_preview(&self, settings: Option<RemotePreviewSettings>) -> std::result::Result<(), String> {
+        if settings.as_ref().map_or(false, |settings| settings.max_bytes == 0) {
+            return Err("Remote preview byte budget must be greater than zero".to_string());
+        }
+        *self.remote_preview.lock().await = settings;
+        Ok(())
+    }
+
+    /// Captures emitted recently, oldest first, so a reloaded frontend can catch up
+    pub fn get_recent_captures(&self) -> Vec<CaptureResult> {
+        self.recent_captures.lock().unwrap().iter().cloned().collect()
+    }
+
+    /// Count busy capture failures and emit `camera:buffer_full` once they pile up
+    /// Bodies rarely report buffer depth, but a buffer that is still flushing rejects new frames as busy
+    fn record_capture_error(&self, app: &AppHandle, error: &str) {
+        if !error.to_lowercase().contains("busy") {
+            self.consecutive_busy.store(0, Ordering::Relaxed);
+            return;
+        }
+
+        let busy_count = self.consecutive_busy.fetch_add(1, Ordering::Relaxed) + 1;
+        if busy_count == BUFFER_FULL_BUSY_THRESHOLD {
+            eprintln!("{} [Camera] Buffer appears full after {} busy captures", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), busy_count);
+            let status = BufferStatus { frames_available: None, full: true, inferred: true };
+            app.emit("camera:buffer_full", &status).ok();
+        }
+    }
+
+    /// Get the camera's buffer status
+    /// Uses the body's reported buffer depth where available, otherwise infers it from busy
+    /// captures; None when the body doesn't report it and no capture has hit a busy camera.
+    pub async fn get_buffer_status(&self) -> std::result::Result<Option<BufferStatus>, String> {
+        let camera = self.connected_camera().await?;
+        let reported = tokio::task::spawn_blocking(move || {
+            BUFFER_KEYS.iter().find_map(|key| {
+                camera.config_key::<gphoto2::widget::RangeWidget>(key)
+                    .wait()
+                    .ok()
+                    .map(|widget| widget.value().max(0.0) as u32)
+            })
+        })
+        .await
+        .map_err(|e| format!("Task join error: {}", e))?;
+
+        if let Some(frames) = reported {
+            return Ok(Some(BufferStatus { frames_available: Some(frames), full: frames == 0, inferred: false }));
+        }
+
+        let busy_count = self.consecutive_busy.load(Ordering::Relaxed);
+        if busy_count == 0 {
+            return Ok(None);
+        }
+        Ok(Some(BufferStatus {
+            frames_available: None,
+            full: busy_count >= BUFFER_FULL_BUSY_THRESHOLD,
+            inferred: true,
+        }))
+    }
+
+    /// Get the body's overheat warning level; None when the body doesn't report one
+    pub async fn get_thermal_status(&self) -> std::result::Result<Option<ThermalStatus>, String> {
+        let camera = self.connected_camera().await?;
+        tokio::task::spawn_blocking(move || {
+            Self::read_config_text(&camera, THERMAL_KEYS).map(|value| ThermalStatus::from_value(&value))
+        })
+        .await
+        .map_err(|e| format!("Task join error: {}", e))
+    }
+
+    /// Last thermal warning level the monitor saw, for long-running captures deciding whether to pause
+    pub fn last_thermal_status(&self) -> ThermalStatus {
+        *self.thermal_status.lock().unwrap()
+    }
 
-            let choices: Vec<String> = widget.choices_iter().map(|c| c.to_string()).collect();
-            Ok(choices)
+    /// Re-read the thermal warning and emit `camera:thermal_warning` when it escalates
+    async fn check_thermal(&self, app: &AppHandle) {
+        let Ok(Some(status)) = self.get_thermal_status().await else {
+            return;
+        };
+        let previous = std::mem::replace(&mut *self.thermal_status.lock().unwrap(), status);
+        if status > previous {
+            eprintln!("{} [Camera] Thermal warning: {:?} -> {:?}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), previous, status);
+            app.emit("camera:thermal_warning", status).ok();
+        }
+    }
+
+    /// Fire `count` captures back to back and time the shutter and the download of each
+    /// Files are downloaded into a temporary folder that is removed afterwards; the frames
+    /// stay on the card and are marked known so the filesystem fallback doesn't pick them up.
+    pub async fn benchmark_capture(&self, count: u32) -> std::result::Result<BenchmarkReport, String> {
+        if count == 0 {
+            return Err("Benchmark needs at least one capture".to_string());
+        }
+        let _operation = self.begin_operation("benchmark").await?;
+        let camera = self.connected_camera().await?;
+        let options = self.options.lock().await.clone();
+        let known_camera_files = self.known_camera_files.clone();
+        let _io = self.camera_io_permit().await;
+
+        tokio::task::spawn_blocking(move || {
+            let temp_dir = std::env::temp_dir().join(format!("rapidraw-benchmark-{}", Uuid::new_v4()));
+            std::fs::create_dir_all(&temp_dir)
+                .map_err(|e| format!("Failed to create benchmark directory: {}", e))?;
+
+            let started = Instant::now();
+            let mut capture_times = Vec::new();
+            let mut download_times = Vec::new();
+            let mut total_bytes = 0u64;
+            let mut first_error = None;
+
+            for shot in 1..=count {
+                let shot_started = Instant::now();
+                let image_path = match Self::fire_shutter(&camera, false, &options) {
+                    Ok(path) => path,
+                    Err(e) => {
+                        eprintln!("{} [Camera] Benchmark shot {}/{} failed: {}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), shot, count, e);
+                        first_error.get_or_insert(e);
+                        continue;
+                    }
+                };
+                capture_times.push(shot_started.elapsed());
+                known_camera_files.lock().unwrap().insert(format!("{}/{}", image_path.folder(), image_path.name()));
+
+                let file_path = temp_dir.join(format!("shot_{}.{}", shot, Self::extract_file_extension(&image_path.name())));
+                let download_started = Instant::now();
+                if let Err(e) = camera.fs().download_to(&image_path.folder(), &image_path.name(), &file_path).wait() {
+                    first_error.get_or_insert(format!("Download failed: {}", e));
+                    continue;
+                }
+                download_times.push(download_started.elapsed());
+                total_bytes += Self::local_file_size(&file_path.to_string_lossy());
+                let _ = std::fs::remove_file(&file_path);
+            }
+            let _ = std::fs::remove_dir_all(&temp_dir);
+
+            let download_secs: f64 = download_times.iter().map(|d| d.as_secs_f64()).sum();
+            let report = BenchmarkReport {
+                shots_requested: count,
+                shots_completed: download_times.len() as u32,
+                capture: TimingStats::from_durations(&capture_times),
+                download: TimingStats::from_durations(&download_times),
+                total_bytes,
+                throughput_mb_per_sec: if download_secs > 0.0 { total_bytes as f64 / download_secs / 1_000_000.0 } else { 0.0 },
+                elapsed_secs: started.elapsed().as_secs_f64(),
+                first_error,
+            };
+            eprintln!("{} [Camera] Benchmark: {}/{} shots, {:.1} MB/s", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), report.shots_completed, count, report.throughput_mb_per_sec);
+            Ok(report)
         })
         .await
         .map_err(|e| format!("Task join error: {}", e))?
     }
 
-    /// Set a configuration parameter value
-    pub async fn set_config_value(&self, config_key: &str, value: &str) -> std::result::Result<(), String> {
-        let camera = {
-            let camera_guard = self.camera.lock().await;
-            camera_guard
-                .as_ref()
-                .ok_or("No camera connected")?
-                .clone()
+    /// Fire the shutter and return as soon as it has, downloading in the background
+    /// Returns a capture id; `camera:captured` carries the same id once the file lands, or
+    /// `camera:capture_failed` if the download fails. The camera stays claimed until then.
+    pub async fn capture_async(self: Arc<Self>, app: AppHandle, target_folder: Option<String>) -> std::result::Result<String, String> {
+        let operation = self.begin_operation("capture").await?;
+        let camera = self.connected_camera().await?;
+
+        let capture_dir = if let Some(folder) = target_folder {
+            *self.current_download_folder.lock().await = Some(folder.clone());
+            PathBuf::from(folder)
+        } else {
+            self.capture_dir.clone()
+        };
+        let options = self.options.lock().await.clone();
+
+        let shutter_camera = camera.clone();
+        let image_path = tokio::task::spawn_blocking(move || Self::fire_shutter(&shutter_camera, false, &options))
+            .await
+            .map_err(|e| format!("Task join error: {}", e))?
+            .map_err(|e| {
+                self.record_capture_error(&app, &e);
+                e
+            })?;
+        self.consecutive_busy.store(0, Ordering::Relaxed);
+        self.remember_camera_file(&image_path.folder(), &image_path.name());
+
+        let capture_id = Uuid::new_v4().to_string();
+        eprintln!("{} [Camera] Shutter fired ({}), downloading in background", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), capture_id);
+
+        let this = self.clone();
+        let id = capture_id.clone();
+        let handle = tokio::spawn(async move {
+            let _operation = operation;
+            let _io = this.camera_io_permit().await;
+            match this.download_camera_file(&app, camera, image_path.folder().to_string(), image_path.name().to_string(), capture_dir).await {
+                Ok(mut result) => {
+                    result.capture_id = Some(id);
+                    this.emit_captured(&app, &result).await;
+                }
+                Err(e) => {
+                    eprintln!("{} [Camera] Background download failed ({}): {}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), id, e);
+                    app.emit("camera:capture_failed", serde_json::json!({ "captureId": id, "error": e })).ok();
+                }
+            }
+        });
+        self.track_task(handle);
+
+        Ok(capture_id)
+    }
+
+    /// Create another handle sharing this service's state, for commands that spawn background work
+    fn shared(&self) -> Arc<Self> {
+        Arc::new(CameraService {
+            camera: self.camera.clone(),
+            capture_dir: self.capture_dir.clone(),
+            current_download_folder: self.current_download_folder.clone(),
+            cached_dimensions: self.cached_dimensions.clone(),
+            cached_params: self.cached_params.clone(),
+            focus_locked: self.focus_locked.clone(),
+            liveview_active: self.liveview_active.clone(),
+            liveview_stop: self.liveview_stop.clone(),
+            liveview_frame: self.liveview_frame.clone(),
+            shutdown: self.shutdown.clone(),
+            background_tasks: self.background_tasks.clone(),
+            options: self.options.clone(),
+            auto_connect_cancelled: self.auto_connect_cancelled.clone(),
+            last_capture_path: self.last_capture_path.clone(),
+            active_session: self.active_session.clone(),
+            active_operation: self.active_operation.clone(),
+            active_port: self.active_port.clone(),
+            params_dirty: self.params_dirty.clone(),
+            capabilities: self.capabilities.clone(),
+            settings_locked: self.settings_locked.clone(),
+            sleeping: self.sleeping.clone(),
+            consecutive_busy: self.consecutive_busy.clone(),
+            pending_config: self.pending_config.clone(),
+            known_camera_files: self.known_camera_files.clone(),
+            active_sequence: self.active_sequence.clone(),
+            interval_state: self.interval_state.clone(),
+            transfer_stats_reported: self.transfer_stats_reported.clone(),
+            camera_io: self.camera_io.clone(),
+            camera_io_permits: self.camera_io_permits.clone(),
+            card_only: self.card_only.clone(),
+            thermal_status: self.thermal_status.clone(),
+            recent_captures: self.recent_captures.clone(),
+            camera_serial: self.camera_serial.clone(),
+            remote_preview: self.remote_preview.clone(),
+            connection_history: self.connection_history.clone(),
+            upload_queue: self.upload_queue.clone(),
+            pending_captures: self.pending_captures.clone(),
+            preferred_port: self.preferred_port.clone(),
+        })
+    }
+
+    /// Shoot `count` calibration frames of one kind into `calibration/<kind>/`
+    /// Each frame is tagged in its sidecar and carries the kind in its CaptureResult. Setting up
+    /// the frames (lens cap, flat panel, shortest shutter for bias) is left to the photographer.
+    pub async fn capture_calibration_set(&self, app: AppHandle, target_folder: Option<String>, kind: CalibrationKind, count: u32) -> std::result::Result<Vec<CaptureResult>, String> {
+        if count == 0 {
+            return Err("Calibration set needs at least one frame".to_string());
+        }
+        let mut results = Vec::with_capacity(count as usize);
+        for frame in 1..=count {
+            let options = CaptureOptions { calibration: Some(kind), ..Default::default() };
+            let result = self.capture_and_download(app.clone(), target_folder.clone(), options)
+                .await
+                .map_err(|e| format!("Calibration frame {}/{} failed: {}", frame, count, e))?;
+            eprintln!("{} [Camera] Calibration {:?} frame {}/{}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), kind, frame, count);
+            results.push(result);
+        }
+        Ok(results)
+    }
+
+    /// Shoot one frame per white balance value, restoring the original white balance afterwards
+    pub async fn capture_wb_bracket(&self, app: AppHandle, target_folder: Option<String>, values: Vec<String>, image_format: Option<String>) -> std::result::Result<Vec<CaptureResult>, String> {
+        let frames = self.capture_setting_bracket(app, target_folder, BracketSetting { keys: WHITE_BALANCE_KEYS, label: "white balance", sequence_prefix: "wb_bracket" }, values, image_format).await?;
+        Ok(frames.into_iter().map(|(_, result)| result).collect())
+    }
+
+    /// Shoot a 3, 5 or 7 frame exposure bracket around `center_ev`, `step_ev` stops apart
+    /// Uses exposure compensation, or shutter speed (relative to the current one) in manual mode
+    /// or when compensation isn't writable. Steps beyond the body's range are clamped and
+    /// duplicates dropped; `camera:bracket_clamped` reports the values actually shot, and each
+    /// frame is tagged with its EV offset. The original setting is restored afterwards.
+    pub async fn capture_bracket(&self, app: AppHandle, target_folder: Option<String>, center_ev: f32, step_ev: f32, frames: u32, image_format: Option<String>) -> std::result::Result<Vec<CaptureResult>, String> {
+        if ![3, 5, 7].contains(&frames) {
+            return Err(format!("Exposure bracket needs 3, 5 or 7 frames, not {}", frames));
+        }
+        if !step_ev.is_finite() || step_ev <= 0.0 {
+            return Err("Exposure bracket step must be positive".to_string());
+        }
+        let camera = self.connected_camera().await?;
+        let BracketPlan { keys, label, frames: plan, clamped } = tokio::task::spawn_blocking(move || Self::plan_exposure_bracket(&camera, center_ev, step_ev, frames))
+            .await
+            .map_err(|e| format!("Task join error: {}", e))??;
+        if clamped {
+            eprintln!("{} [Camera] Exposure bracket clamped to the body's range: {} of {} frames", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), plan.len(), frames);
+            app.emit("camera:bracket_clamped", serde_json::json!({
+                "requested": frames,
+                "shot": plan.iter().map(|(_, value)| value.clone()).collect::<Vec<_>>(),
+            })).ok();
+        }
+
+        let values = plan.iter().map(|(_, value)| value.clone()).collect();
+        let shots = self.capture_setting_bracket(app, target_folder, BracketSetting { keys, label, sequence_prefix: "exposure_bracket" }, values, image_format).await?;
+        Ok(shots
+            .into_iter()
+            .map(|(value, result)| {
+                if let Some((ev, _)) = plan.iter().find(|(_, planned)| *planned == value) {
+                    Self::add_capture_tag(&result.file_path, &format!("EV {:+.1}", ev));
+                }
+                result
+            })
+            .collect())
+    }
+
+    /// Pick the setting and the choice for each frame of an exposure bracket
+    fn plan_exposure_bracket(camera: &Camera, center_ev: f32, step_ev: f32, frames: u32) -> std::result::Result<BracketPlan, String> {
+        let offsets: Vec<f32> = (0..frames)
+            .map(|frame| center_ev + (frame as f32 - (frames / 2) as f32) * step_ev)
+            .collect();
+
+        let manual = Self::get_radio_value(camera, SHOOTING_MODE_KEYS)
+            .as_deref()
+            .and_then(ExposureProgram::from_mode)
+            == Some(ExposureProgram::Manual);
+        let compensation = Self::read_config_setting(camera, EXPOSURE_COMPENSATION_KEYS).filter(|setting| {
+            camera.config_key::<gphoto2::widget::RadioWidget>(&setting.key)
+                .wait()
+                .map(|widget| !widget.readonly())
+                .unwrap_or(false)
+        });
+
+        // Choices as (stops, label), in the same scale as the offsets
+        let (keys, label, steps): (&'static [&'static str], &'static str, Vec<(f32, String)>) = match compensation {
+            Some(setting) if !manual => {
+                let steps = setting.choices
+                    .iter()
+                    .filter_map(|choice| exposure_target::parse_ev(choice).map(|ev| (ev, choice.clone())))
+                    .collect();
+                (EXPOSURE_COMPENSATION_KEYS, "exposure compensation", steps)
+            }
+            _ => {
+                let setting = Self::read_config_setting(camera, SHUTTER_SPEED_KEYS)
+                    .ok_or_else(|| "Camera exposes neither writable exposure compensation nor shutter speed".to_string())?;
+                let current = exposure_target::parse_shutter_seconds(&setting.value)
+                    .ok_or_else(|| format!("Can't bracket from shutter speed '{}'", setting.value))?;
+                // Work in stops from the current shutter speed so the comparison is like for like
+                let steps = setting.choices
+                    .iter()
+                    .filter_map(|choice| {
+                        exposure_target::parse_shutter_seconds(choice).map(|seconds| ((seconds / current).log2(), choice.clone()))
+                    })
+                    .collect();
+                (SHUTTER_SPEED_KEYS, "shutter speed", steps)
+            }
+        };
+        if steps.is_empty() {
+            return Err(format!("Camera reports no numeric {} values", label));
+        }
+
+        let (min, max) = steps.iter().fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), (ev, _)| (min.min(*ev), max.max(*ev)));
+        let mut clamped = false;
+        let mut plan: Vec<(f32, String)> = Vec::with_capacity(offsets.len());
+        for offset in offsets {
+            let target = offset.clamp(min, max);
+            clamped |= target != offset;
+            let (_, choice) = steps
+                .iter()
+                .min_by(|a, b| (a.0 - target).abs().partial_cmp(&(b.0 - target).abs()).unwrap_or(std::cmp::Ordering::Equal))
+                .cloned()
+                .ok_or_else(|| format!("Camera reports no numeric {} values", label))?;
+            if plan.iter().any(|(_, planned)| *planned == choice) {
+                clamped = true;
+                continue;
+            }
+            plan.push((offset, choice));
+        }
+        Ok(BracketPlan { keys, label, frames: plan, clamped })
+    }
+
+    /// Shoot one frame per ISO with everything else fixed, for comparing a body's noise
+    /// Each frame is tagged with its ISO in the sidecar; the original ISO is restored afterwards.
+    pub async fn capture_iso_sweep(&self, app: AppHandle, target_folder: Option<String>, isos: Vec<String>, image_format: Option<String>) -> std::result::Result<Vec<CaptureResult>, String> {
+        let frames = self.capture_setting_bracket(app, target_folder, BracketSetting { keys: ISO_KEYS, label: "ISO", sequence_prefix: "iso_sweep" }, isos, image_format).await?;
+        Ok(frames
+            .into_iter()
+            .map(|(iso, result)| {
+                Self::add_capture_tag(&result.file_path, &format!("ISO {}", iso));
+                result
+            })
+            .collect())
+    }
+
+    /// Shoot one frame per value of a setting, restoring the original value afterwards
+    /// Values the body rejects are skipped; a failed capture stops the bracket. Either way the
+    /// original setting is restored, and the frames share one sequence id for grouping.
+    /// The camera stays claimed for the whole bracket, so no other capture lands between values.
+    /// With `image_format` the whole bracket is shot in that format, restored on every exit.
+    async fn capture_setting_bracket(&self, app: AppHandle, target_folder: Option<String>, setting: BracketSetting, values: Vec<String>, image_format: Option<String>) -> std::result::Result<Vec<(String, CaptureResult)>, String> {
+        if values.is_empty() {
+            return Err(format!("{} bracket needs at least one value", setting.label));
+        }
+        let camera = self.connected_camera().await?;
+        let _operation = self.begin_operation("bracket").await?;
+        let original_format = self.apply_image_format(image_format.as_deref()).await?;
+        let result = self.shoot_setting_bracket(app, target_folder, camera, setting, values).await;
+        self.restore_image_format(original_format).await;
+        result
+    }
+
+    /// The frames of capture_setting_bracket; the caller holds the operation and image format
+    async fn shoot_setting_bracket(&self, app: AppHandle, target_folder: Option<String>, camera: Camera, setting: BracketSetting, values: Vec<String>) -> std::result::Result<Vec<(String, CaptureResult)>, String> {
+        let BracketSetting { keys, label, sequence_prefix } = setting;
+        let read_camera = camera.clone();
+        let original = tokio::task::spawn_blocking(move || Self::read_config_setting(&read_camera, keys))
+            .await
+            .map_err(|e| format!("Task join error: {}", e))?
+            .ok_or_else(|| format!("Camera does not expose a {} setting", label))?;
+
+        let sequence_id = format!("{}_{}", sequence_prefix, chrono::Local::now().format("%Y%m%d_%H%M%S"));
+        let mut results = Vec::with_capacity(values.len());
+        let mut failure = None;
+        for value in &values {
+            let set_camera = camera.clone();
+            let key = original.key.clone();
+            let wanted = value.clone();
+            let set = tokio::task::spawn_blocking(move || Self::write_config_setting(&set_camera, &key, &wanted))
+                .await
+                .map_err(|e| format!("Task join error: {}", e))?;
+            if let Err(e) = set {
+                eprintln!("{} [Camera] Skipping {} '{}': {}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), label, value, e);
+                continue;
+            }
+
+            match self.capture_frame(app.clone(), target_folder.clone(), CaptureOptions::default()).await {
+                Ok(mut result) => {
+                    result.sequence_id = Some(sequence_id.clone());
+                    results.push((value.clone(), result));
+                }
+                Err(e) => {
+                    failure = Some(format!("{} bracket failed at '{}': {}", label, value, e));
+                    break;
+                }
+            }
+        }
+
+        let restore = tokio::task::spawn_blocking(move || Self::write_config_setting(&camera, &original.key, &original.value))
+            .await
+            .map_err(|e| format!("Task join error: {}", e))?;
+        if let Err(e) = restore {
+            eprintln!("{} [Camera] Failed to restore {}: {}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), label, e);
+        }
+
+        if let Some(e) = failure {
+            return Err(e);
+        }
+        if results.is_empty() {
+            return Err(format!("Camera rejected every {} value: {:?}", label, values));
+        }
+        eprintln!("{} [Camera] {} bracket complete: {} of {} values in {}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), label, results.len(), values.len(), sequence_id);
+        Ok(results)
+    }
+
+    /// Adjust exposure compensation until a preview's mean brightness (0..1) is near the target, then capture
+    /// Stops after AUTO_EXPOSURE_MAX_ITERATIONS previews, or earlier once the correction is within
+    /// tolerance or the compensation range runs out. The compensation is left on the final value.
+    /// Compensation only shifts exposure in P/A/S modes; in manual it has no effect on the preview.
+    pub async fn capture_auto_exposure(&self, app: AppHandle, target_folder: Option<String>, target_mean: f32) -> std::result::Result<AutoExposureResult, String> {
+        if !(target_mean > 0.0 && target_mean < 1.0) {
+            return Err(format!("Target brightness {} must be between 0 and 1", target_mean));
+        }
+        let camera = self.connected_camera().await?;
+        let (exposure_compensation, ev, preview_mean, iterations) = {
+            let _operation = self.begin_operation("auto exposure").await?;
+            tokio::task::spawn_blocking(move || Self::meter_to_target(&camera, target_mean))
+                .await
+                .map_err(|e| format!("Task join error: {}", e))??
         };
+        eprintln!("{} [Camera] Exposed to target {:.2}: {} EV after {} previews (mean {:.2})", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), target_mean, exposure_compensation, iterations, preview_mean);
 
-        let key = config_key.to_string();
-        let value = value.to_string();
-        tokio::task::spawn_blocking(move || {
-            let widget = camera.config_key::<gphoto2::widget::RadioWidget>(&key)
-                .wait()
-                .map_err(|e| format!("Failed to get config '{}': {}", key, e))?;
+        let capture = self.capture_and_download(app, target_folder, CaptureOptions::default()).await?;
+        Ok(AutoExposureResult { capture, exposure_compensation, ev, preview_mean, iterations })
+    }
+
+    /// Step exposure compensation toward the target mean, returning (choice, ev, mean, previews taken)
+    fn meter_to_target(camera: &Camera, target_mean: f32) -> std::result::Result<(String, f32, f32, u32), String> {
+        let setting = Self::read_config_setting(camera, EXPOSURE_COMPENSATION_KEYS)
+            .ok_or_else(|| "Camera does not expose exposure compensation".to_string())?;
+        let steps: Vec<(f32, String)> = setting.choices
+            .iter()
+            .filter_map(|choice| exposure_target::parse_ev(choice).map(|ev| (ev, choice.clone())))
+            .collect();
+        if steps.is_empty() {
+            return Err("Camera reports no numeric exposure compensation values".to_string());
+        }
 
-            // Check if readonly
-            if widget.readonly() {
-                return Err(format!("Config '{}' is readonly", key));
+        let context = Self::new_context()?;
+        let mut current = setting.value.clone();
+        let mut current_ev = exposure_target::parse_ev(&current).unwrap_or(0.0);
+        let mut mean = 0.0;
+        let mut iterations = 0;
+        while iterations < AUTO_EXPOSURE_MAX_ITERATIONS {
+            iterations += 1;
+            let frame = camera.capture_preview()
+                .wait()
+                .map_err(|e| format!("Failed to capture preview: {}", e))?
+                .get_data(&context)
+                .wait()
+                .map_err(|e| format!("Failed to read preview: {}", e))?;
+            mean = exposure_target::mean_brightness(&frame)?;
+            let correction = exposure_target::ev_correction(mean, target_mean);
+            if correction.abs() < AUTO_EXPOSURE_TOLERANCE_EV {
+                break;
             }
 
-            widget.set_choice(&value)
-                .map_err(|e| format!("Failed to set choice '{}' for '{}': {}", value, key, e))?;
+            let wanted = current_ev + correction;
+            let (ev, choice) = steps
+                .iter()
+                .min_by(|a, b| (a.0 - wanted).abs().partial_cmp(&(b.0 - wanted).abs()).unwrap_or(std::cmp::Ordering::Equal))
+                .cloned()
+                .unwrap_or((current_ev, current.clone()));
+            // Already at the end of the range or the closest step
+            if choice == current {
+                break;
+            }
+            Self::write_config_setting(camera, &setting.key, &choice)?;
+            current = choice;
+            current_ev = ev;
+            // Give the preview a moment to reflect the new compensation
+            std::thread::sleep(Duration::from_millis(300));
+        }
+        Ok((current, current_ev, mean, iterations))
+    }
 
-            camera.set_config(&widget)
-                .wait()
-                .map_err(|e| format!("Failed to apply config '{}': {}", key, e))?;
+    /// Shoot into a temp area and return a preview; the frame is kept only once confirm_capture is called
+    /// Nothing is emitted or recorded for the session until then, and discard_capture deletes it.
+    pub async fn capture_pending(&self, app: AppHandle, target_folder: Option<String>) -> std::result::Result<PendingCapture, String> {
+        let _operation = self.begin_operation("capture").await?;
+        let camera = self.connected_camera().await?;
+        let options = self.options.lock().await.clone();
+        let known_camera_files = self.known_camera_files.clone();
+        let pending_id = Uuid::new_v4().to_string();
+        let temp_dir = std::env::temp_dir().join("rapidraw-pending");
 
-            // Small delay to let camera process the change
-            std::thread::sleep(std::time::Duration::from_millis(100));
+        let io_permit = self.camera_io_permit().await;
+        let id = pending_id.clone();
+        let pending = tokio::task::spawn_blocking(move || {
+            let image_path = Self::fire_shutter(&camera, false, &options)?;
+            let (folder, name) = (image_path.folder().to_string(), image_path.name().to_string());
+            known_camera_files.lock().unwrap().insert(format!("{}/{}", folder, name));
 
-            Ok(())
+            std::fs::create_dir_all(&temp_dir)
+                .map_err(|e| format!("Failed to create pending capture directory: {}", e))?;
+            let temp_path = temp_dir.join(format!("{}.{}", id, Self::extract_file_extension(&name)));
+            let camera_file_size = Self::download_verified(&camera, &folder, &name, &temp_path, options.verify_downloads)?;
+            Ok::<PendingFile, String>(PendingFile {
+                temp_path,
+                original_name: name,
+                target_folder,
+                camera_model: camera.abilities().model().to_string(),
+                camera_file_size,
+            })
         })
         .await
         .map_err(|e| format!("Task join error: {}", e))?
-    }
+        .map_err(|e| {
+            self.record_capture_error(&app, &e);
+            format!("Capture error: {}", e)
+        })?;
+        drop(io_permit);
+        self.consecutive_busy.store(0, Ordering::Relaxed);
 
-    /// Capture a photo and download it directly to target folder
-    pub async fn capture_and_download(&self, app: AppHandle, target_folder: Option<String>) -> std::result::Result<CaptureResult, String> {
-        let camera = {
-            let camera_guard = self.camera.lock().await;
-            camera_guard
-                .as_ref()
-                .ok_or("No camera connected")?
-                .clone()
+        let temp_path = pending.temp_path.to_string_lossy().to_string();
+        let preview_app = app.clone();
+        let preview_path = temp_path.clone();
+        let preview = tokio::task::spawn_blocking(move || {
+            let image = get_cached_or_generate_thumbnail_image(&preview_path, &preview_app, None).map_err(|e| e.to_string())?;
+            let settings = RemotePreviewSettings { format: PreviewFormat::Webp, max_bytes: PENDING_PREVIEW_BYTES };
+            remote_preview::encode_within_budget(&image, &settings, &preview_path)
+        })
+        .await
+        .map_err(|e| format!("Task join error: {}", e))?;
+        let preview = match preview {
+            Ok(preview) => preview,
+            Err(e) => {
+                let _ = std::fs::remove_file(&pending.temp_path);
+                return Err(format!("Failed to preview pending capture: {}", e));
+            }
         };
 
-        // Use target folder if provided, otherwise use default capture dir
-        let capture_dir = if let Some(ref folder) = target_folder {
-            // Store this as the current download folder for camera button captures
-            *self.current_download_folder.lock().await = Some(folder.clone());
-            std::path::PathBuf::from(folder)
-        } else {
-            self.capture_dir.clone()
-        };
+        self.pending_captures.lock().unwrap().insert(pending_id.clone(), pending);
+        eprintln!("{} [Camera] Capture {} waiting for confirmation", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), pending_id);
+        Ok(PendingCapture { pending_id, preview })
+    }
 
-        // Add timeout to prevent blocking (60 seconds for camera to respond)
-        let capture_result = tokio::time::timeout(
-            tokio::time::Duration::from_secs(60),
-            tokio::task::spawn_blocking(move || {
-                eprintln!("{} [Camera] Capturing photo...", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"));
-                // Capture with minimal retry logic
-                let result = camera.capture_image().wait();
-                let image_path = match result {
-                    Ok(path) => path,
-                    Err(e) => {
-                        let error_msg = e.to_string().to_lowercase();
-                        // Only retry on specific transient I/O errors
-                        if error_msg.contains("i/o in progress") {
-                            std::thread::sleep(std::time::Duration::from_secs(1));
-                            let retry_result = camera.capture_image().wait();
-                            match retry_result {
-                                Ok(path) => path,
-                                Err(retry_e) => {
-                                    return Err(format!("Capture failed after retry: {}", retry_e));
-                                }
-                            }
-                        } else {
-                            return Err(format!("Capture failed: {}", e));
-                        }
-                    }
-                };
+    /// Move a pending capture into its download folder and treat it like any other capture
+    pub async fn confirm_capture(&self, app: AppHandle, pending_id: &str) -> std::result::Result<CaptureResult, String> {
+        let pending = self.pending_captures.lock().unwrap().remove(pending_id)
+            .ok_or_else(|| format!("No pending capture '{}'", pending_id))?;
 
-                // Get file info
-                let original_name = image_path.name();
-                let ext = Self::extract_file_extension(&original_name);
+        let base = match &pending.target_folder {
+            Some(folder) => PathBuf::from(folder),
+            None => self.capture_dir.clone(),
+        };
+        let (capture_dir, session_id) = self.resolve_capture_dir(base).await;
+        let options = self.options.lock().await.clone();
+        let ext = Self::extract_file_extension(&pending.original_name);
+        let pair_dir = capture_dir.clone();
+        let capture_dir = Self::dir_for_file_type(&capture_dir, &ext, &options, session_id.as_deref());
+        std::fs::create_dir_all(&capture_dir)
+            .map_err(|e| format!("Failed to create capture directory: {}", e))?;
+        let file_path = Self::capture_file_path(&capture_dir, &pending.original_name, &ext, &options, None)?;
 
-                // Generate filename with timestamp
-                let timestamp = SystemTime::now()
-                    .duration_since(SystemTime::UNIX_EPOCH)
-                    .map_err(|e| format!("Time error: {}", e))?
-                    .as_secs();
+        // The temp area may be on another volume, where a rename can't move the file
+        if std::fs::rename(&pending.temp_path, &file_path).is_err() {
+            std::fs::copy(&pending.temp_path, &file_path)
+                .map_err(|e| format!("Failed to move pending capture to {}: {}", file_path.display(), e))?;
+            let _ = std::fs::remove_file(&pending.temp_path);
+        }
 
-                let name = format!("capture_{:010}.{}", timestamp, ext);
-                let file_path = capture_dir.join(&name);
+        let (width, height) = Self::get_image_dimensions(&file_path)
+            .unwrap_or_else(|| sensor_dimensions::fallback_dimensions(&pending.camera_model));
+        let file_path = file_path.to_string_lossy().to_string();
+        let (raw_file_size, jpg_file_size) = Self::paired_file_sizes(&file_path, &pair_dir, &options, session_id.as_deref());
+        let checksum = if options.verify_downloads {
+            Self::file_checksum(&file_path)
+        } else {
+            None
+        };
+        self.write_capture_sidecar(&file_path, None).await;
+        let orientation = self.capture_orientation(&file_path).await;
+        let previous_capture = self.record_capture(&file_path).await;
+        let result = CaptureResult {
+            format: CaptureFormat::from_paths(&[&file_path]),
+            previous_capture,
+            session_id,
+            orientation,
+            file_size: Self::local_file_size(&file_path),
+            raw_file_size,
+            jpg_file_size,
+            camera_file_size: pending.camera_file_size,
+            checksum,
+            capture_id: Some(pending_id.to_string()),
+            sequence_id: None,
+            aspect_ratio: self.cached_aspect_ratio().await,
+            calibration: None,
+            af_point: None,
+            file_path,
+            raw_path: None,
+            jpg_path: None,
+            preview_path: None,
+            width,
+            height,
+        };
+        eprintln!("{} [Camera] Pending capture {} kept as {}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), pending_id, result.file_path);
+        self.emit_captured(&app, &result).await;
+        Ok(result)
+    }
 
-                // Ensure capture directory exists
-                std::fs::create_dir_all(&capture_dir)
-                    .map_err(|e| format!("Failed to create capture directory: {}", e))?;
+    /// Delete a pending capture without keeping it; the card copy, if any, is left alone
+    pub fn discard_capture(&self, pending_id: &str) -> std::result::Result<(), String> {
+        let pending = self.pending_captures.lock().unwrap().remove(pending_id)
+            .ok_or_else(|| format!("No pending capture '{}'", pending_id))?;
+        std::fs::remove_file(&pending.temp_path)
+            .map_err(|e| format!("Failed to delete pending capture: {}", e))?;
+        eprintln!("{} [Camera] Pending capture {} discarded", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), pending_id);
+        Ok(())
+    }
 
-                // Download the file
-                let fs = camera.fs();
-                eprintln!("{} [Camera] Downloading file...", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"));
-                fs.download_to(&image_path.folder(), &image_path.name(), &file_path)
-                    .wait()
-                    .map_err(|e| format!("Download failed: {}", e))?;
-                eprintln!("{} [Camera] Downloaded to: {}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), file_path.display());
+    /// Fire one frame immediately into the current download folder
+    /// Safe to call from an external trigger (GPIO, network message): it goes through the same
+    /// operation guard as UI captures, so a trigger that lands mid-capture gets a Busy error
+    /// instead of colliding. To drive it from a channel, forward each message to this method:
+    ///
+    /// ```ignore
+    /// let (tx, mut rx) = tokio::sync::mpsc::channel::<()>(8);
+    /// tokio::spawn(async move {
+    ///     while rx.recv().await.is_some() {
+    ///         if let Err(e) = service.trigger_capture(app.clone()).await {
+    ///             eprintln!("External trigger failed: {}", e);
+    ///         }
+    ///     }
+    /// });
+    /// // From the listener: tx.send(()).await
+    /// ```
+    pub async fn trigger_capture(&self, app: AppHandle) -> std::result::Result<CaptureResult, String> {
+        let target_folder = self.current_download_folder.lock().await.clone();
+        self.capture_and_download(app, target_folder, CaptureOptions::default()).await
+    }
 
-                // Get dimensions - use cached value or quick check, fall back to default
-                // For RAW files, use default dimensions immediately to avoid blocking
-                let ext = file_path.extension().and_then(|e| e.to_str()).unwrap_or("").to_lowercase();
-                let is_raw = matches!(ext.as_str(), "cr3" | "cr2" | "nef" | "arw" | "dng" | "raf" | "orf" | "pef" | "rw2" | "srw");
+    /// List attached cameras as model and port pairs, for picking one in connect_camera
+    pub async fn list_cameras(&self) -> std::result::Result<Vec<DeviceInfo>, String> {
+        self.enumerate_devices().await
+    }
 
-                // For RAW files, use default dimensions to avoid blocking
-                // For JPEG, try to get actual dimensions quickly
-                let dimensions = if is_raw {
-                    // Use default dimensions for RAW - avoids slow rawler parsing
-                    eprintln!("{} [Camera] Using default dimensions for RAW file", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"));
-                    (1920, 1080)
-                } else {
-                    // For JPEG, quick image crate check
-                    Self::get_image_dimensions(&file_path).unwrap_or((1920, 1080))
-                };
+    /// List attached cameras for a device picker without opening any of them
+    pub async fn enumerate_devices(&self) -> std::result::Result<Vec<DeviceInfo>, String> {
+        let active_port = match self.camera.lock().await.as_ref() {
+            Some(_) => self.active_port.lock().await.clone(),
+            None => None,
+        };
+        tokio::task::spawn_blocking(move || {
+            let context = Self::new_context()?;
+            let devices = context.list_cameras()
+                .wait()
+                .map_err(|e| format!("Failed to list cameras: {}", e))?
+                .map(|descriptor| DeviceInfo {
+                    connected: active_port.as_deref() == Some(descriptor.port.as_str()),
+                    model: descriptor.model,
+                    port: descriptor.port,
+                })
+                .collect();
+            Ok::<Vec<DeviceInfo>, String>(devices)
+        })
+        .await
+        .map_err(|e| format!("Task join error: {}", e))?
+    }
 
-                Ok::<(PathBuf, u32, u32), String>((file_path, dimensions.0, dimensions.1))
+    /// Open the camera enumerated on `port`; None if nothing is attached there
+    /// PTP/IP ports are never enumerated, so they are opened directly.
+    fn open_camera_on_port(context: &Context, port: &str) -> std::result::Result<Option<Camera>, String> {
+        let descriptor = if port.starts_with(PTPIP_PORT_PREFIX) {
+            Some(gphoto2::list::CameraDescriptor {
+                model: PTPIP_GENERIC_MODEL.to_string(),
+                port: port.to_string(),
             })
-        ).await
-        .map_err(|e| format!("Task join error: {}", e))?;  // Handle JoinError
-
-        // Handle both timeout and capture errors
-        let (file_path, width, height) = match capture_result {
-            Ok(inner_result) => inner_result.map_err(|e| format!("Capture error: {}", e))?,
-            Err(_) => return Err("Capture timeout after 60 seconds. Camera may be disconnected or busy.".to_string()),
+        } else {
+            context.list_cameras()
+                .wait()
+                .map_err(|e| format!("Failed to list cameras: {}", e))?
+                .find(|descriptor| descriptor.port == port)
         };
+        let Some(descriptor) = descriptor else {
+            return Ok(None);
+        };
+        context.get_camera(&descriptor)
+            .wait()
+            .map(Some)
+            .map_err(|e| format!("Failed to open {} on {}: {}", descriptor.model, port, e))
+    }
 
-        // Emit capture complete event
-        app.emit("camera:captured", serde_json::json!({
-            "filePath": file_path.to_string_lossy().to_string(),
-            "width": width,
-            "height": height,
-        })).ok();
-
-        Ok(CaptureResult {
-            file_path: file_path.to_string_lossy().to_string(),
-            raw_path: None,
-            jpg_path: None,
-            preview_path: None,
-            width,
-            height,
-        })
+    /// Connect to a camera over WiFi/Ethernet (PTP/IP) at `ip`
+    /// The body must already be in its PC-connection mode and paired with this host. The address
+    /// is remembered like a picked USB port, so auto-connect retries it after a drop.
+    pub async fn connect_network_camera(&self, app: AppHandle, ip: String) -> std::result::Result<CameraParams, String> {
+        let address: std::net::IpAddr = ip.trim()
+            .parse()
+            .map_err(|_| format!("'{}' is not an IP address", ip))?;
+        self.connect_camera(app, Some(format!("{}{}", PTPIP_PORT_PREFIX, address))).await
     }
 
     /// Auto-detect and connect to camera (hot-plug support)
     pub async fn auto_connect(&self, app: AppHandle) -> std::result::Result<CameraParams, String> {
-        // Try to detect camera with multiple attempts
+        let release_usb = self.options.lock().await.release_usb_on_connect;
+
+        // A camera picked by port is the only one auto-connect reopens
+        let preferred_port = self.preferred_port.lock().await.clone();
+        if preferred_port.as_deref().map_or(false, |port| port.starts_with(PTPIP_PORT_PREFIX))
+            && !self.options.lock().await.reconnect_network_camera
+        {
+            return Err("Network camera reconnect is disabled".to_string());
+        }
+
+        // Try to detect camera with multiple attempts, reporting the last specific failure
+        let mut last_error = None;
         for attempt in 1..=5 {
+            if self.auto_connect_cancelled.load(Ordering::Relaxed) || self.shutdown.load(Ordering::Relaxed) {
+                return Err("Auto-connect cancelled".to_string());
+            }
+
+            // Only release once per auto-connect round, the monitor calls this repeatedly
+            let release_now = release_usb && attempt == 1;
+            let preferred_port = preferred_port.clone();
             let result: std::result::Result<Option<(Camera, String)>, String> = tokio::task::spawn_blocking(move || {
-                let context = Context::new().map_err(|e| format!("Failed to create context: {}", e))?;
+                if release_now {
+                    Self::release_usb_claims();
+                }
+
+                let context = Self::new_context()?;
+
+                if let Some(port) = preferred_port {
+                    return Ok(Self::open_camera_on_port(&context, &port)?.map(|camera| {
+                        let model = camera.abilities().model().to_string();
+                        (camera, model)
+                    }));
+                }
 
                 // Try to autodetect
                 match context.autodetect_camera().wait() {
@@ -481,9 +5177,12 @@ impl CameraService {
                         Ok::<Option<(Camera, String)>, String>(Some((camera, model)))
                     }
                     Err(e) => {
-                        let error_msg = e.to_string().to_lowercase();
-                        if error_msg.contains("could not claim") || error_msg.contains("usb") {
-                            Err(format!("USB occupied - close other camera apps"))
+                        if Self::is_usb_claim_error(&e.to_string()) {
+                            if release_usb {
+                                Err(format!("USB still occupied after releasing gvfs - close other camera apps"))
+                            } else {
+                                Err(format!("USB occupied - close other camera apps"))
+                            }
                         } else {
                             Ok(None)
                         }
@@ -493,21 +5192,29 @@ impl CameraService {
             .await
             .map_err(|e| format!("Task join error: {}", e))?;
 
-            if let Ok(Some((camera, _model))) = result {
-                // Store camera
-                *self.camera.lock().await = Some(camera);
+            match result {
+                Ok(Some((camera, _model))) => {
+                    // Store camera
+                    let port = Self::camera_port(&camera);
+                    *self.camera.lock().await = Some(camera);
+                    *self.active_port.lock().await = Some(port);
 
-                // Verify connection by actually getting params
-                match self.get_camera_params_internal().await {
-                    Ok(params) => {
-                        app.emit("camera:status", "Connected").ok();
-                        return Ok(params);
-                    }
-                    Err(_e) => {
-                        *self.camera.lock().await = None;
-                        // Continue to next attempt
+                    // Verify connection by actually getting params
+                    match self.get_camera_params_internal().await {
+                        Ok(params) => {
+                            self.on_connected(&app, ConnectionReason::Auto).await;
+                            app.emit("camera:status", "Connected").ok();
+                            return Ok(params);
+                        }
+                        Err(e) => {
+                            *self.camera.lock().await = None;
+                            // Continue to next attempt
+                            last_error = Some(format!("Camera detected but not responding: {}", e));
+                        }
                     }
                 }
+                Ok(None) => {}
+                Err(e) => last_error = Some(e),
             }
 
             if attempt < 5 {
@@ -515,29 +5222,402 @@ impl CameraService {
             }
         }
 
-        Err("No camera detected".to_string())
+        Err(last_error.unwrap_or_else(|| "No camera detected".to_string()))
+    }
+
+    /// Connect to another attached camera after the active one was lost
+    /// Skips the failed camera's port in case it is still enumerated but unresponsive
+    async fn failover_to_next_camera(&self, app: &AppHandle, failed_port: String) -> std::result::Result<CameraParams, String> {
+        let (camera, model, port) = tokio::task::spawn_blocking(move || {
+            let context = Self::new_context()?;
+
+            let descriptor = context.list_cameras()
+                .wait()
+                .map_err(|e| format!("Failed to list cameras: {}", e))?
+                .find(|descriptor| descriptor.port != failed_port)
+                .ok_or_else(|| "No other camera available for failover".to_string())?;
+
+            let camera = context.get_camera(&descriptor)
+                .wait()
+                .map_err(|e| format!("Failed to open {}: {}", descriptor.model, e))?;
+
+            Ok::<(Camera, String, String), String>((camera, descriptor.model, descriptor.port))
+        })
+        .await
+        .map_err(|e| format!("Task join error: {}", e))??;
+
+        *self.camera.lock().await = Some(camera);
+        *self.active_port.lock().await = Some(port.clone());
+
+        match self.get_camera_params_internal().await {
+            Ok(params) => {
+                // The lost camera itself may come back on another port (hub re-enumeration after sleep)
+                let lost_serial = self.camera_serial.lock().await.clone();
+                if lost_serial.is_some() && params.serial_number == lost_serial {
+                    self.on_connected(app, ConnectionReason::Auto).await;
+                    eprintln!("{} [Camera] {} reconnected on new port {}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), model, port);
+                    app.emit("camera:status", "Connected").ok();
+                    return Ok(params);
+                }
+
+                self.on_connected(app, ConnectionReason::Failover).await;
+                eprintln!("{} [Camera] Failed over to {} on {}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), model, port);
+                app.emit("camera:failover", serde_json::json!({
+                    "model": model,
+                    "port": port,
+                })).ok();
+                app.emit("camera:status", "Connected").ok();
+                Ok(params)
+            }
+            Err(e) => {
+                *self.camera.lock().await = None;
+                Err(e)
+            }
+        }
+    }
+
+    /// Pause the monitor's background reads so nothing perturbs carefully dialed-in settings
+    /// Some quirky bodies glitch during polling; event monitoring for button captures keeps running
+    pub fn lock_settings(&self) {
+        self.settings_locked.store(true, Ordering::Relaxed);
+        eprintln!("{} [Camera] Settings locked, background polling paused", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"));
+    }
+
+    /// Resume the monitor's background reads
+    pub fn unlock_settings(&self) {
+        self.settings_locked.store(false, Ordering::Relaxed);
+        eprintln!("{} [Camera] Settings unlocked, background polling resumed", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"));
+    }
+
+    /// Set the body's power-save toggle (or On/Off radio) where it exposes one
+    fn set_power_save(camera: &Camera, enabled: bool) -> std::result::Result<(), String> {
+        for key in POWER_SAVE_KEYS {
+            if let Ok(widget) = camera.config_key::<gphoto2::widget::ToggleWidget>(key).wait() {
+                widget.set_toggled(enabled);
+                return camera.set_config(&widget)
+                    .wait()
+                    .map_err(|e| format!("Failed to apply config '{}': {}", key, e));
+            }
+            if camera.config_key::<gphoto2::widget::RadioWidget>(key).wait().is_ok() {
+                return Self::set_radio_value(camera, key, if enabled { "On" } else { "Off" });
+            }
+        }
+        Err("Camera does not support remote sleep".to_string())
+    }
+
+    /// Put the camera into power-save mode between shots
+    pub async fn camera_sleep(&self) -> std::result::Result<(), String> {
+        let camera = self.connected_camera().await?;
+        tokio::task::spawn_blocking(move || Self::set_power_save(&camera, true))
+            .await
+            .map_err(|e| format!("Task join error: {}", e))??;
+
+        self.sleeping.store(true, Ordering::Relaxed);
+        eprintln!("{} [Camera] Camera put to sleep", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"));
+        Ok(())
+    }
+
+    /// Wake the camera and wait until it answers config reads again
+    /// Bodies without a power-save widget wake on any config read, so that is tried as well
+    pub async fn camera_wake(&self) -> std::result::Result<(), String> {
+        let camera = self.connected_camera().await?;
+        let woken = tokio::task::spawn_blocking(move || {
+            let _ = Self::set_power_save(&camera, false);
+
+            let deadline = Instant::now() + CAMERA_WAKE_LATENCY * 2;
+            while Instant::now() < deadline {
+                if Self::get_radio_value(&camera, ISO_KEYS).is_some() || Self::responds_outside_capture(&camera) {
+                    return Ok(());
+                }
+                std::thread::sleep(Duration::from_millis(250));
+            }
+            Err("Camera did not wake up in time".to_string())
+        })
+        .await
+        .map_err(|e| format!("Task join error: {}", e))
+        .and_then(|woken| woken);
+
+        // Let the monitor poll again either way; a body that stays asleep then shows as unresponsive
+        self.sleeping.store(false, Ordering::Relaxed);
+        woken?;
+        eprintln!("{} [Camera] Camera woken up", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"));
+        Ok(())
+    }
+
+    /// Sleep the camera until the next interval frame, waking it early enough to shoot on time
+    /// Skips sleeping when the gap is shorter than the wake latency; bodies that can't sleep just wait.
+    /// Returns false if the interval was stopped meanwhile (the camera is still woken up).
+    async fn sleep_until_frame(&self, next_frame: Instant, cancel: &tokio::sync::Notify) -> std::result::Result<bool, String> {
+        let wake_at = next_frame.checked_sub(CAMERA_WAKE_LATENCY);
+        let Some(wake_at) = wake_at.filter(|wake_at| *wake_at > Instant::now()) else {
+            return Ok(Self::interval_wait(cancel, next_frame).await);
+        };
+
+        let slept = self.camera_sleep().await.is_ok();
+        let running = Self::interval_wait(cancel, wake_at).await;
+        if slept {
+            self.camera_wake().await?;
+        }
+        Ok(running && Self::interval_wait(cancel, next_frame).await)
+    }
+
+    /// Wait for the next interval frame; returns false as soon as the interval is stopped
+    async fn interval_wait(cancel: &tokio::sync::Notify, until: Instant) -> bool {
+        tokio::select! {
+            _ = tokio::time::sleep_until(until.into()) => true,
+            _ = cancel.notified() => false,
+        }
+    }
+
+    /// Register a new interval run, returning the handle its loop waits on for cancellation
+    async fn begin_interval(&self, folder: Option<String>) -> std::result::Result<Arc<tokio::sync::Notify>, String> {
+        let mut state = self.interval_state.lock().await;
+        if state.is_some() {
+            return Err("An interval capture is already running".to_string());
+        }
+        let cancel = Arc::new(tokio::sync::Notify::new());
+        *state = Some(IntervalState {
+            started: Instant::now(),
+            folder,
+            frames_captured: 0,
+            frames_failed: 0,
+            cancel: cancel.clone(),
+        });
+        Ok(cancel)
+    }
+
+    /// Count a frame of the running interval
+    async fn record_interval_frame(&self, captured: bool) {
+        if let Some(state) = self.interval_state.lock().await.as_mut() {
+            if captured {
+                state.frames_captured += 1;
+            } else {
+                state.frames_failed += 1;
+            }
+        }
+    }
+
+    /// Finish the interval run registered with `cancel`, returning its summary
+    /// None if it was already stopped, or replaced by a newer run that must be left alone
+    async fn end_interval(&self, cancel: &Arc<tokio::sync::Notify>) -> Option<IntervalSummary> {
+        let mut state = self.interval_state.lock().await;
+        if !state.as_ref().map_or(false, |state| Arc::ptr_eq(&state.cancel, cancel)) {
+            return None;
+        }
+        state.take().map(|state| state.summary())
+    }
+
+    /// Shoot a timelapse every `interval_secs`, for `count` frames or until stopped
+    /// Frames are scheduled on a fixed grid from the start, so slow downloads don't add drift;
+    /// a slot missed because the previous frame overran is skipped rather than shot late.
+    /// Stops on disconnect and emits `camera:timelapse_complete` with the summary either way.
+    /// With `image_format` every frame is shot in that format, restored when the timelapse ends.
+    /// With the `sleep_between_frames` option the body is put to sleep between frames.
+    pub async fn start_timelapse(self: Arc<Self>, app: AppHandle, target_folder: Option<String>, interval_secs: f64, count: Option<u32>, image_format: Option<String>) -> std::result::Result<(), String> {
+        if !interval_secs.is_finite() || interval_secs <= 0.0 {
+            return Err("Timelapse interval must be positive".to_string());
+        }
+        if count == Some(0) {
+            return Err("Timelapse needs at least one frame".to_string());
+        }
+        self.connected_camera().await?;
+        let original_format = self.apply_image_format(image_format.as_deref()).await?;
+        let cancel = match self.begin_interval(target_folder.clone()).await {
+            Ok(cancel) => cancel,
+            Err(e) => {
+                self.restore_image_format(original_format).await;
+                return Err(e);
+            }
+        };
+
+        let sleep_between_frames = self.options.lock().await.sleep_between_frames;
+
+        let this = self.clone();
+        let handle = tokio::spawn(async move {
+            let interval = Duration::from_secs_f64(interval_secs);
+            let started = Instant::now();
+            let mut summary = IntervalSummary {
+                frames_captured: 0,
+                frames_failed: 0,
+                elapsed_secs: 0.0,
+                folder: target_folder.clone(),
+            };
+            let mut slot: u32 = 0;
+            loop {
+                if count.map_or(false, |count| summary.frames_captured + summary.frames_failed >= count) {
+                    break;
+                }
+                if this.shutdown.load(Ordering::Relaxed) {
+                    break;
+                }
+                if this.camera.lock().await.is_none() {
+                    eprintln!("{} [Camera] Timelapse stopped: camera disconnected", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"));
+                    break;
+                }
+
+                if this.last_thermal_status() == ThermalStatus::Critical {
+                    eprintln!("{} [Camera] Timelapse frame skipped: body is overheating", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"));
+                } else {
+                    let captured = this.capture_and_download(app.clone(), target_folder.clone(), CaptureOptions::default()).await;
+                    if let Err(e) = &captured {
+                        eprintln!("{} [Camera] Timelapse frame failed: {}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), e);
+                    }
+                    if captured.is_ok() {
+                        summary.frames_captured += 1;
+                    } else {
+                        summary.frames_failed += 1;
+                    }
+                    this.record_interval_frame(captured.is_ok()).await;
+                }
+
+                // Next slot on the grid that hasn't passed yet
+                let elapsed = started.elapsed().as_secs_f64();
+                slot = (slot + 1).max((elapsed / interval_secs).ceil() as u32);
+                let next_frame = started + interval * slot;
+                let waited = if sleep_between_frames {
+                    this.sleep_until_frame(next_frame, &cancel).await
+                } else {
+                    Ok(Self::interval_wait(&cancel, next_frame).await)
+                };
+                match waited {
+                    Ok(true) => {}
+                    Ok(false) => break,
+                    Err(e) => {
+                        eprintln!("{} [Camera] Timelapse wake failed: {}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), e);
+                        if !Self::interval_wait(&cancel, next_frame).await {
+                            break;
+                        }
+                    }
+                }
+            }
+
+            // Already cleared when stopped through stop_interval
+            this.end_interval(&cancel).await;
+            this.restore_image_format(original_format).await;
+            summary.elapsed_secs = started.elapsed().as_secs_f64();
+            eprintln!("{} [Camera] Timelapse finished: {} captured, {} failed", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), summary.frames_captured, summary.frames_failed);
+            app.emit("camera:timelapse_complete", &summary).ok();
+        });
+        self.track_task(handle);
+
+        eprintln!("{} [Camera] Timelapse started: every {}s, {}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), interval_secs, count.map_or("until stopped".to_string(), |count| format!("{} frames", count)));
+        Ok(())
+    }
+
+    /// Stop a running interval/timelapse immediately and summarize it
+    /// Interrupts the inter-frame wait right away; a frame already being captured still finishes.
+    pub async fn stop_interval(&self) -> std::result::Result<IntervalSummary, String> {
+        let state = self.interval_state
+            .lock()
+            .await
+            .take()
+            .ok_or_else(|| "No interval capture is running".to_string())?;
+        // notify_one keeps a permit, so a loop that is mid-capture stops at its next wait
+        state.cancel.notify_one();
+
+        let summary = state.summary();
+        eprintln!("{} [Camera] Interval stopped: {} captured, {} failed", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), summary.frames_captured, summary.frames_failed);
+        Ok(summary)
+    }
+
+    /// Stop auto-connect retries until the next manual connect
+    pub fn cancel_auto_connect(&self) {
+        self.auto_connect_cancelled.store(true, Ordering::Relaxed);
+        eprintln!("{} [Camera] Auto-connect cancelled", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"));
+    }
+
+    /// Remember a spawned background loop so stop_all can wait for it
+    fn track_task(&self, handle: tokio::task::JoinHandle<()>) {
+        let mut tasks = self.background_tasks.lock().unwrap();
+        tasks.retain(|task| !task.is_finished());
+        tasks.push(handle);
+    }
+
+    /// Stop all background tasks (connection monitor, event monitor, live view, timelapse)
+    /// Returns only after the tasks have actually exited, not just been signaled; a task that
+    /// doesn't exit within 5s is aborted
+    pub async fn stop_all(&self) -> std::result::Result<(), String> {
+        self.shutdown.store(true, Ordering::Relaxed);
+        self.liveview_active.store(false, Ordering::Relaxed);
+        // A timelapse waits on its cancel handle between frames, not on the shutdown flag
+        let _ = self.stop_interval().await;
+
+        let handles: Vec<_> = self.background_tasks.lock().unwrap().drain(..).collect();
+        for mut handle in handles {
+            // Loops check the flag every tick; a blocked gphoto call is bounded by its own timeout
+            if tokio::time::timeout(Duration::from_secs(5), &mut handle).await.is_err() {
+                // Cancel it at its current await point rather than leave it running
+                handle.abort();
+                let _ = handle.await;
+                eprintln!("{} [Camera] Background task did not stop in time, aborted", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"));
+            }
+        }
+
+        eprintln!("{} [Camera] All background tasks stopped", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"));
+        Ok(())
     }
 
     /// Start background monitoring for camera connection
     pub async fn start_monitoring(self: Arc<Self>, app: AppHandle) -> std::result::Result<(), String> {
         // Track if event monitoring is running to avoid duplicate spawns
-        use std::sync::atomic::{AtomicBool, Ordering};
         let event_monitoring_active = Arc::new(AtomicBool::new(false));
         let event_monitoring_active_clone = event_monitoring_active.clone();
 
-        tokio::spawn(async move {
+        // A previous stop_all shouldn't keep new monitoring from running
+        self.shutdown.store(false, Ordering::Relaxed);
+
+        let this = self.clone();
+        let handle = tokio::spawn(async move {
             let mut interval = tokio::time::interval(tokio::time::Duration::from_millis(500));
             let mut was_connected = false;
+            // Back off auto-connect after repeated failures instead of hammering every tick
+            let mut connect_failures: u32 = 0;
+            let mut next_connect_attempt = Instant::now();
+            let mut next_thermal_check = Instant::now();
             loop {
                 interval.tick().await;
 
+                if self.shutdown.load(Ordering::Relaxed) {
+                    break;
+                }
+
                 // Check if camera is connected
                 let is_connected = self.camera.lock().await.is_some();
 
                 if !is_connected {
                     was_connected = false;
+                    if self.auto_connect_cancelled.load(Ordering::Relaxed) || Instant::now() < next_connect_attempt {
+                        continue;
+                    }
+
+                    // The active camera was lost (not user-disconnected) - try the next one first
+                    let failed_port = self.active_port.lock().await.clone();
+                    if let Some(failed_port) = failed_port {
+                        if self.options.lock().await.failover
+                            && self.failover_to_next_camera(&app, failed_port).await.is_ok()
+                        {
+                            connect_failures = 0;
+                            continue;
+                        }
+                    }
+
                     // Camera not connected - try to auto-connect
-                    let _ = self.auto_connect(app.clone()).await;
+                    match self.auto_connect(app.clone()).await {
+                        Ok(_) => {
+                            connect_failures = 0;
+                        }
+                        Err(e) => {
+                            connect_failures = connect_failures.saturating_add(1);
+                            let backoff = AUTO_CONNECT_BASE_INTERVAL
+                                .saturating_mul(1 << connect_failures.min(6))
+                                .min(AUTO_CONNECT_MAX_INTERVAL);
+                            next_connect_attempt = Instant::now() + backoff;
+                            // Log the first failure only so an idle station doesn't flood the log
+                            if connect_failures == 1 {
+                                eprintln!("{} [Camera] Auto-connect failed: {} (backing off)", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), e);
+                            }
+                        }
+                    }
                 } else {
                     // Camera is connected
                     // Start event monitoring if it wasn't running before (reconnect scenario)
@@ -546,14 +5626,39 @@ impl CameraService {
                         let self_clone = self.clone();
                         let app_clone = app.clone();
                         let active_flag = event_monitoring_active_clone.clone();
-                        tokio::spawn(async move {
+                        let event_handle = tokio::spawn(async move {
                             self_clone.start_event_monitoring_with_flag(app_clone, active_flag).await;
                         });
+                        self.track_task(event_handle);
                     }
                     was_connected = true;
 
-                    // Camera is connected, verify it's still responsive
-                    match self.get_camera_params().await {
+                    // Live view refreshes params itself, avoid competing with the preview stream
+                    if self.liveview_active.load(Ordering::Relaxed) {
+                        continue;
+                    }
+
+                    // Settings lock: leave the camera alone until explicitly unlocked
+                    // A sleeping camera doesn't answer reads, polling it would look like a disconnect
+                    if self.settings_locked.load(Ordering::Relaxed) || self.sleeping.load(Ordering::Relaxed) {
+                        continue;
+                    }
+
+                    if Instant::now() >= next_thermal_check {
+                        next_thermal_check = Instant::now() + THERMAL_CHECK_INTERVAL;
+                        self.check_thermal(&app).await;
+                    }
+
+                    // Full refresh only after the camera reported a property change
+                    if self.params_dirty.swap(false, Ordering::Relaxed) {
+                        if let Ok(params) = self.get_camera_params_internal().await {
+                            app.emit("camera:params", &params).ok();
+                        }
+                        continue;
+                    }
+
+                    // Camera is connected, verify it's still responsive with a single cheap read
+                    match self.probe_camera().await {
                         Ok(_) => {}
                         Err(e) => {
                             // Check if this is a disconnection error (PTP/IO errors)
@@ -568,7 +5673,9 @@ impl CameraService {
                             // Immediate disconnect on first critical error
                             if is_disconnect_error {
                                 eprintln!("{} [Camera] Disconnected: {}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), e);
+                                self.record_connection_event(&app, false, ConnectionReason::from_error(&error_msg), Some(e.to_string())).await;
                                 *self.camera.lock().await = None;
+                                *self.cached_params.lock().await = None;
                                 let _ = app.emit("camera:status", "Disconnected");
                                 was_connected = false;
                             }
@@ -577,27 +5684,348 @@ impl CameraService {
                 }
             }
         });
+        this.track_task(handle);
 
         Ok(())
     }
 
+    /// Render the camera folder template for the connected body, if one is configured
+    async fn camera_folder(&self) -> Option<PathBuf> {
+        let template = self.options.lock().await.camera_folder_template.clone()?;
+        let model = self.camera.lock().await.as_ref()?.abilities().model().to_string();
+        let serial = self.camera_serial.lock().await.clone().unwrap_or_else(|| model.clone());
+        // Keep each value a single path component; the template's own slashes still nest
+        let component = |value: &str| value.trim().replace(['/', '\\', ':'], "_");
+        let folder = template
+            .replace("{serial}", &component(&serial))
+            .replace("{model}", &component(&model));
+        let folder = folder.trim_matches(|c| c == '/' || c == '\\');
+        (!folder.is_empty()).then(|| PathBuf::from(folder))
+    }
+
+    /// Apply the camera folder and the active session to a capture folder
+    /// Returns the folder captures should land in and the session id to tag them with
+    async fn resolve_capture_dir(&self, base: PathBuf) -> (PathBuf, Option<String>) {
+        let base = match self.camera_folder().await {
+            Some(folder) => base.join(folder),
+            None => base,
+        };
+        let session_id = self.active_session.lock().await.clone();
+        match session_id {
+            Some(id) => (base.join(&id), Some(id)),
+            None => (base, None),
+        }
+    }
+
+    /// List the captures already on disk for the current download folder and session
+    /// Rebuilds the filmstrip after a reconnect or UI reload. RAW+JPEG pairs are merged with the
+    /// JPEG as preview; RAW-only files use the camera's sensor size instead of a slow RAW decode.
+    pub async fn list_session_captures(&self) -> std::result::Result<Vec<CaptureResult>, String> {
+        let base = match self.current_download_folder.lock().await.clone() {
+            Some(folder) => PathBuf::from(folder),
+            None => self.capture_dir.clone(),
+        };
+        let (capture_dir, session_id) = self.resolve_capture_dir(base).await;
+        let options = self.options.lock().await.clone();
+        let camera_model = match self.camera.lock().await.as_ref() {
+            Some(camera) => camera.abilities().model().to_string(),
+            None => String::new(),
+        };
+
+        // Per-type overrides put part of the roll in other folders; "dng" stands in for any RAW
+        let mut dirs = vec![capture_dir.clone()];
+        for ext in ["dng", "jpg"] {
+            let dir = Self::dir_for_file_type(&capture_dir, ext, &options, session_id.as_deref());
+            if !dirs.contains(&dir) {
+                dirs.push(dir);
+            }
+        }
+
+        let mut captures = tokio::task::spawn_blocking(move || {
+            let mut groups: std::collections::HashMap<String, Vec<PathBuf>> = std::collections::HashMap::new();
+            for dir in &dirs {
+                let Ok(entries) = std::fs::read_dir(dir) else {
+                    continue;
+                };
+                for path in entries.filter_map(Result::ok).map(|entry| entry.path()) {
+                    if !path.is_file()
+                        || !formats::is_supported_image_file(&path.to_string_lossy())
+                        || path.file_name().map_or(false, |name| name == contact_sheet::CONTACT_SHEET_FILE_NAME)
+                    {
+                        continue;
+                    }
+                    let stem = path.file_stem().unwrap_or_default().to_string_lossy().to_string();
+                    groups.entry(stem).or_default().push(path);
+                }
+            }
+
+            let mut captures: Vec<(SystemTime, CaptureResult)> = groups
+                .into_values()
+                .map(|paths| {
+                    let paths: Vec<String> = paths.iter().map(|p| p.to_string_lossy().to_string()).collect();
+                    let path_refs: Vec<&str> = paths.iter().map(|p| p.as_str()).collect();
+                    let raw_path = paths.iter().find(|p| Self::is_raw_file(p)).cloned();
+                    let jpg_path = paths.iter().find(|p| {
+                        let lower = p.to_lowercase();
+                        lower.ends_with(".jpg") || lower.ends_with(".jpeg")
+                    }).cloned();
+                    let file_path = raw_path.clone().or_else(|| jpg_path.clone()).unwrap_or_else(|| paths[0].clone());
+                    let paired = raw_path.is_some() && jpg_path.is_some();
+
+                    let dimensions = match (&jpg_path, &raw_path) {
+                        (Some(jpg), _) => Self::get_image_dimensions(&PathBuf::from(jpg)),
+                        (None, Some(_)) => None,
+                        (None, None) => Self::get_image_dimensions(&PathBuf::from(&file_path)),
+                    }
+                    .unwrap_or_else(|| sensor_dimensions::fallback_dimensions(&camera_model));
+                    let modified = std::fs::metadata(&file_path)
+                        .and_then(|m| m.modified())
+                        .unwrap_or(SystemTime::UNIX_EPOCH);
+
+                    let result = CaptureResult {
+                        format: CaptureFormat::from_paths(&path_refs),
+                        previous_capture: None,
+                        session_id: None,
+                        orientation: Self::read_exif_orientation(&file_path),
+                        file_size: Self::local_file_size(&file_path),
+                        raw_file_size: raw_path.as_deref().filter(|_| paired).map(Self::local_file_size),
+                        jpg_file_size: jpg_path.as_deref().filter(|_| paired).map(Self::local_file_size),
+                        camera_file_size: None,
+                        checksum: None,
+                        capture_id: None,
+                        sequence_id: None,
+                        aspect_ratio: None,
+                        calibration: None,
+                        af_point: None,
+                        preview_path: if paired { jpg_path.clone() } else { None },
+                        raw_path: if paired { raw_path } else { None },
+                        jpg_path: if paired { jpg_path } else { None },
+                        file_path,
+                        width: dimensions.0,
+                        height: dimensions.1,
+                    };
+                    (modified, result)
+                })
+                .collect();
+
+            captures.sort_by_key(|(modified, _)| *modified);
+            captures.into_iter().map(|(_, result)| result).collect::<Vec<CaptureResult>>()
+        })
+        .await
+        .map_err(|e| format!("Task join error: {}", e))?;
+
+        // Chain previous_capture the same way live captures are
+        let mut previous: Option<String> = None;
+        for capture in &mut captures {
+            capture.session_id = session_id.clone();
+            capture.previous_capture = previous.replace(capture.file_path.clone());
+        }
+
+        Ok(captures)
+    }
+
+    /// Start a capture session; captures go into `<target>/<session_id>/` until it ends
+    pub async fn start_session(&self, name: &str) -> std::result::Result<String, String> {
+        // Keep the id filesystem-safe regardless of what the user typed
+        let sanitized: String = name
+            .trim()
+            .chars()
+            .map(|c| if c.is_alphanumeric() || c == '-' || c == '_' { c } else { '_' })
+            .collect();
+        let timestamp = chrono::Local::now().format("%Y%m%d_%H%M%S");
+        let session_id = if sanitized.is_empty() {
+            format!("session_{}", timestamp)
+        } else {
+            format!("{}_{}", sanitized, timestamp)
+        };
+
+        *self.active_session.lock().await = Some(session_id.clone());
+        eprintln!("{} [Camera] Session started: {}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), session_id);
+
+        // Review drops some bodies into playback after each shot, hiding capture settings
+        if self.options.lock().await.disable_review_on_session && self.camera.lock().await.is_some() {
+            if let Err(e) = self.disable_image_review().await {
+                eprintln!("{} [Camera] Failed to disable image review: {}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), e);
+            }
+        }
+        if self.options.lock().await.quiet_on_session && self.camera.lock().await.is_some() {
+            if let Err(e) = self.silence_camera().await {
+                eprintln!("{} [Camera] Failed to silence beep/AF-assist: {}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), e);
+            }
+        }
+        Ok(session_id)
+    }
+
+    /// End the active session, returning its id
+    pub async fn end_session(&self, app: &AppHandle) -> Option<String> {
+        let ended = self.active_session.lock().await.take();
+        if let Some(ref id) = ended {
+            eprintln!("{} [Camera] Session ended: {}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), id);
+            *self.remote_preview.lock().await = None;
+
+            if self.options.lock().await.contact_sheet_on_session_end {
+                let base = match self.current_download_folder.lock().await.clone() {
+                    Some(folder) => PathBuf::from(folder),
+                    None => self.capture_dir.clone(),
+                };
+                let session_folder = base.join(id).to_string_lossy().to_string();
+                if let Err(e) = self.generate_contact_sheet(app.clone(), session_folder, 6, 320).await {
+                    eprintln!("{} [Camera] Failed to generate contact sheet: {}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), e);
+                }
+            }
+        }
+        ended
+    }
+
+    /// Tile a session folder's images into `contact_sheet.jpg` inside that folder
+    /// Thumbnails come from the library's thumbnail cache, so RAW files don't need a full decode;
+    /// for RAW+JPEG pairs only one frame is shown.
+    pub async fn generate_contact_sheet(&self, app: AppHandle, session_folder: String, cols: u32, thumb_size: u32) -> std::result::Result<PathBuf, String> {
+        if cols == 0 || thumb_size == 0 {
+            return Err("Contact sheet needs at least one column and a non-zero thumbnail size".to_string());
+        }
+
+        tokio::task::spawn_blocking(move || {
+            let folder = PathBuf::from(&session_folder);
+            let entries = std::fs::read_dir(&folder)
+                .map_err(|e| format!("Failed to read '{}': {}", session_folder, e))?;
+
+            // One frame per stem, oldest first
+            let mut frames: std::collections::HashMap<String, (SystemTime, PathBuf)> = std::collections::HashMap::new();
+            for path in entries.filter_map(Result::ok).map(|entry| entry.path()) {
+                if !path.is_file()
+                    || !formats::is_supported_image_file(&path.to_string_lossy())
+                    || path.file_name().map_or(false, |name| name == contact_sheet::CONTACT_SHEET_FILE_NAME)
+                {
+                    continue;
+                }
+                let stem = path.file_stem().unwrap_or_default().to_string_lossy().to_string();
+                let modified = std::fs::metadata(&path)
+                    .and_then(|m| m.modified())
+                    .unwrap_or(SystemTime::UNIX_EPOCH);
+                frames.entry(stem).or_insert((modified, path));
+            }
+            let mut frames: Vec<(SystemTime, PathBuf)> = frames.into_values().collect();
+            frames.sort_by_key(|(modified, _)| *modified);
+
+            let thumbnails: Vec<image_crate::DynamicImage> = frames
+                .iter()
+                .filter_map(|(_, path)| get_cached_or_generate_thumbnail_image(&path.to_string_lossy(), &app, None).ok())
+                .collect();
+            if thumbnails.is_empty() {
+                return Err(format!("No images to put on a contact sheet in '{}'", session_folder));
+            }
+
+            let sheet = contact_sheet::tile(&thumbnails, cols, thumb_size);
+            let sheet_path = folder.join(contact_sheet::CONTACT_SHEET_FILE_NAME);
+            contact_sheet::write_jpeg(&sheet, &sheet_path)?;
+            eprintln!("{} [Camera] Contact sheet with {} frames written to {}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), thumbnails.len(), sheet_path.display());
+            Ok(sheet_path)
+        })
+        .await
+        .map_err(|e| format!("Task join error: {}", e))?
+    }
+
+    /// Write the XMP sidecar for a RAW capture when enabled
+    /// Uses cached params so the sidecar doesn't cost an extra round of USB reads
+    async fn write_capture_sidecar(&self, file_path: &str, rating: Option<u8>) {
+        if !self.options.lock().await.write_xmp_sidecar || !Self::is_raw_file(file_path) {
+            return;
+        }
+
+        let params = match self.get_camera_params(false).await {
+            Ok(params) => params,
+            Err(e) => {
+                eprintln!("{} [Camera] Skipping XMP sidecar, params unavailable: {}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), e);
+                return;
+            }
+        };
+
+        if let Err(e) = xmp::write_sidecar(std::path::Path::new(file_path), &params, rating, chrono::Local::now()) {
+            eprintln!("{} [Camera] Failed to write XMP sidecar: {}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), e);
+        }
+    }
+
+    /// Write a capture-time rating and color label into the editor's .rrdata sidecar
+    /// so they show up when the file is opened in the editor
+    fn write_capture_rating(file_path: &str, rating: Option<u8>, color_label: Option<&str>) {
+        if rating.is_none() && color_label.is_none() {
+            return;
+        }
+
+        Self::update_capture_metadata(file_path, |metadata| {
+            if let Some(rating) = rating {
+                let rating = rating.min(5);
+                metadata.rating = rating;
+                // The editor reads the rating back from the adjustments as well
+                if !metadata.adjustments.is_object() {
+                    metadata.adjustments = serde_json::json!({});
+                }
+                metadata.adjustments["rating"] = serde_json::json!(rating);
+            }
+
+            if let Some(color) = color_label.filter(|c| !c.is_empty()) {
+                let mut tags = metadata.tags.take().unwrap_or_default();
+                tags.retain(|tag| !tag.starts_with(COLOR_TAG_PREFIX));
+                tags.push(format!("{}{}", COLOR_TAG_PREFIX, color));
+                metadata.tags = Some(tags);
+            }
+        });
+    }
+
+    /// Add a tag to the editor's sidecar for a capture
+    fn add_capture_tag(file_path: &str, tag: &str) {
+        Self::update_capture_metadata(file_path, |metadata| {
+            let tags = metadata.tags.get_or_insert_with(Vec::new);
+            if !tags.iter().any(|existing| existing == tag) {
+                tags.push(tag.to_string());
+            }
+        });
+    }
+
+    /// Read-modify-write the editor's sidecar for a capture
+    fn update_capture_metadata(file_path: &str, update: impl FnOnce(&mut ImageMetadata)) {
+        let (_, sidecar_path) = parse_virtual_path(file_path);
+        let mut metadata: ImageMetadata = std::fs::read_to_string(&sidecar_path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+
+        update(&mut metadata);
+
+        match serde_json::to_string_pretty(&metadata) {
+            Ok(json_string) => {
+                if let Err(e) = std::fs::write(&sidecar_path, json_string) {
+                    eprintln!("{} [Camera] Failed to write capture sidecar: {}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), e);
+                }
+            }
+            Err(e) => {
+                eprintln!("{} [Camera] Failed to serialize capture sidecar: {}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), e);
+            }
+        }
+    }
+
+    /// Store a new capture as the latest and return the one it replaces
+    async fn record_capture(&self, file_path: &str) -> Option<String> {
+        self.last_capture_path.lock().await.replace(file_path.to_string())
+    }
+
     /// Download a file from the camera and return the result
     async fn download_camera_file(
         &self,
+        app: &AppHandle,
         camera: Camera,
         folder: String,
         name: String,
         capture_dir: PathBuf,
-    ) -> std::result::Result<(String, u32, u32), String> {
+    ) -> std::result::Result<CaptureResult, String> {
+        let (capture_dir, session_id) = self.resolve_capture_dir(capture_dir).await;
         let ext = Self::extract_file_extension(&name);
 
-        let timestamp = SystemTime::now()
-            .duration_since(SystemTime::UNIX_EPOCH)
-            .map_err(|e| format!("Time error: {}", e))?
-            .as_secs();
-
-        let new_name = format!("capture_{:010}.{}", timestamp, ext);
-        let file_path = capture_dir.join(&new_name);
+        let options = self.options.lock().await.clone();
+        let pair_dir = capture_dir.clone();
+        let capture_dir = Self::dir_for_file_type(&capture_dir, &ext, &options, session_id.as_deref());
+        let file_path = Self::capture_file_path(&capture_dir, &name, &ext, &options, None)?;
 
         // Ensure capture directory exists
         std::fs::create_dir_all(&capture_dir)
@@ -607,17 +6035,13 @@ impl CameraService {
         let camera_model = camera.abilities().model().to_string();
 
         // Check cache first for faster response
-        let dimensions = {
-            let cache = self.cached_dimensions.lock().await;
-            cache.get(&camera_model).copied()
-        };
+        let dimensions = self.cached_dimensions.lock().await.get(&camera_model);
 
         // Use camera filesystem to download the file
-        let fs = camera.fs();
         eprintln!("{} [Camera] Downloading from camera button...", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"));
-        fs.download_to(&folder, &name, &file_path)
-            .wait()
-            .map_err(|e| format!("Download failed: {}", e))?;
+        let download_started = Instant::now();
+        let camera_file_size = Self::download_verified(&camera, &folder, &name, &file_path, options.verify_downloads)?;
+        let download_time = download_started.elapsed();
         eprintln!("{} [Camera] Downloaded to: {}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), file_path.display());
 
         // Get dimensions - use cached value if available, otherwise parse and cache
@@ -626,23 +6050,524 @@ impl CameraService {
         } else {
             // Parse and cache for next time
             let dim = Self::get_image_dimensions(&file_path)
-                .unwrap_or((1920, 1080));
+                .unwrap_or_else(|| sensor_dimensions::fallback_dimensions(&camera_model));
             // Cache for next time
-            {
-                let mut cache = self.cached_dimensions.lock().await;
-                cache.insert(camera_model.clone(), dim);
-            }
+            self.cached_dimensions.lock().await.insert(camera_model.clone(), dim, options.dimension_cache_size);
             dim
         };
 
-        Ok((file_path.to_string_lossy().to_string(), dimensions.0, dimensions.1))
+        let file_path = file_path.to_string_lossy().to_string();
+        let (raw_file_size, jpg_file_size) = Self::paired_file_sizes(&file_path, &pair_dir, &options, session_id.as_deref());
+        self.report_transfer_stats(app, Self::local_file_size(&file_path), download_time);
+        let checksum = if options.verify_downloads {
+            Self::file_checksum(&file_path)
+        } else {
+            None
+        };
+        self.write_capture_sidecar(&file_path, None).await;
+        let orientation = self.capture_orientation(&file_path).await;
+        let previous_capture = self.record_capture(&file_path).await;
+        let sequence_id = self.active_sequence.lock().await.as_mut().map(|(id, frames)| {
+            *frames += 1;
+            id.clone()
+        });
+        Ok(CaptureResult {
+            format: CaptureFormat::from_paths(&[&file_path]),
+            previous_capture,
+            session_id,
+            orientation,
+            file_size: Self::local_file_size(&file_path),
+            raw_file_size,
+            jpg_file_size,
+            camera_file_size,
+            checksum,
+            capture_id: None,
+            sequence_id,
+            aspect_ratio: self.cached_aspect_ratio().await,
+            calibration: None,
+            af_point: self.get_af_point().await.ok().flatten(),
+            file_path,
+            raw_path: None,
+            jpg_path: None,
+            preview_path: None,
+            width: dimensions.0,
+            height: dimensions.1,
+        })
+    }
+
+    /// Set a numeric body setting given as a range or as numeric radio choices
+    fn set_numeric_setting(camera: &Camera, keys: &[&str], value: u32) -> std::result::Result<(), String> {
+        for key in keys {
+            if let Ok(widget) = camera.config_key::<gphoto2::widget::RangeWidget>(key).wait() {
+                widget.set_value(value as f32)
+                    .map_err(|e| format!("Failed to set value '{}' for '{}': {}", value, key, e))?;
+                return camera.set_config(&widget)
+                    .wait()
+                    .map_err(|e| format!("Failed to apply config '{}': {}", key, e));
+            }
+            if let Ok(widget) = camera.config_key::<gphoto2::widget::RadioWidget>(key).wait() {
+                let choice = widget.choices_iter()
+                    .find(|choice| Self::parse_config_number(choice) == Some(value as f32))
+                    .ok_or_else(|| format!("Value {} not available for '{}'", value, key))?;
+                return Self::set_radio_value(camera, key, &choice);
+            }
+        }
+        Err(format!("Camera does not expose any of {:?}", keys))
+    }
+
+    /// Switch in-camera focus bracketing on or off, returning the previous setting to restore
+    fn set_focus_bracketing(camera: &Camera, enabled: bool) -> std::result::Result<ConfigSetting, String> {
+        let setting = Self::read_config_setting(camera, FOCUS_BRACKET_ENABLE_KEYS)
+            .ok_or_else(|| "Camera does not support in-camera focus bracketing".to_string())?;
+        let wanted = setting.choices
+            .iter()
+            .find(|choice| {
+                let lower = choice.to_lowercase();
+                if enabled { lower == "on" || lower.starts_with("enable") } else { lower == "off" || lower.starts_with("disable") }
+            })
+            .ok_or_else(|| format!("No {} choice for '{}'", if enabled { "on" } else { "off" }, setting.key))?;
+        Self::write_config_setting(camera, &setting.key, wanted)?;
+        Ok(setting)
+    }
+
+    /// Shoot a focus stack from the current focus, stepping the lens with drive_focus between frames
+    /// Steps are lens drive increments, not distances, so the spacing varies from lens to lens;
+    /// pick a size by trial. Needs live view for the focus drive. Stops at the first failed capture
+    /// or drive. Emits `camera:focus_stack_progress` after each frame. The camera stays claimed
+    /// for the whole stack; live view keeps the lens drive available but sends no frames meanwhile.
+    /// With `image_format` the stack is shot in that format, restored however the stack ends.
+    pub async fn capture_focus_stack(&self, app: AppHandle, target_folder: Option<String>, frames: u32, direction: FocusDirection, step: FocusStep, image_format: Option<String>) -> std::result::Result<Vec<CaptureResult>, String> {
+        if frames == 0 {
+            return Err("Focus stack needs at least one frame".to_string());
+        }
+        let _operation = self.begin_operation("focus stack").await?;
+        let original_format = self.apply_image_format(image_format.as_deref()).await?;
+        let result = self.shoot_focus_stack(app, target_folder, frames, direction, step).await;
+        self.restore_image_format(original_format).await;
+        result
+    }
+
+    /// The frames of capture_focus_stack; the caller holds the operation and image format
+    async fn shoot_focus_stack(&self, app: AppHandle, target_folder: Option<String>, frames: u32, direction: FocusDirection, step: FocusStep) -> std::result::Result<Vec<CaptureResult>, String> {
+        let sequence_id = format!("focus_stack_{}", chrono::Local::now().format("%Y%m%d_%H%M%S"));
+        let mut results = Vec::with_capacity(frames as usize);
+        for frame in 1..=frames {
+            if frame > 1 {
+                self.drive_focus(direction, step)
+                    .await
+                    .map_err(|e| format!("Focus stack stopped before frame {}/{}: {}", frame, frames, e))?;
+                tokio::time::sleep(FOCUS_DRIVE_SETTLE).await;
+            }
+
+            let mut result = self.capture_frame(app.clone(), target_folder.clone(), CaptureOptions::default())
+                .await
+                .map_err(|e| format!("Focus stack frame {}/{} failed: {}", frame, frames, e))?;
+            result.sequence_id = Some(sequence_id.clone());
+            app.emit("camera:focus_stack_progress", serde_json::json!({
+                "frame": frame,
+                "total": frames,
+                "filePath": result.file_path,
+                "sequenceId": sequence_id,
+            })).ok();
+            results.push(result);
+        }
+
+        eprintln!("{} [Camera] Focus stack complete: {} frames in {}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), frames, sequence_id);
+        Ok(results)
+    }
+
+    /// Shoot an in-camera focus bracket and wait for the event monitor to download the burst
+    /// All frames share one sequence id. Requires event monitoring to be running, since the
+    /// body delivers the burst as NewFile events rather than as a capture result.
+    pub async fn capture_native_focus_bracket(&self, shots: u32, step: u32) -> std::result::Result<String, String> {
+        if shots == 0 {
+            return Err("Focus bracket needs at least one shot".to_string());
+        }
+        let _operation = self.begin_operation("focus bracket").await?;
+        let camera = self.connected_camera().await?;
+
+        let sequence_id = format!("focus_bracket_{}", chrono::Local::now().format("%Y%m%d_%H%M%S"));
+        *self.active_sequence.lock().await = Some((sequence_id.clone(), 0));
+
+        let trigger_camera = camera.clone();
+        let triggered = tokio::task::spawn_blocking(move || {
+            let original = Self::set_focus_bracketing(&trigger_camera, true)?;
+            let result = Self::set_numeric_setting(&trigger_camera, FOCUS_BRACKET_SHOTS_KEYS, shots)
+                .and_then(|_| Self::set_numeric_setting(&trigger_camera, FOCUS_BRACKET_STEP_KEYS, step))
+                .and_then(|_| trigger_camera.trigger_capture().wait().map_err(|e| format!("Failed to trigger focus bracket: {}", e)));
+            Ok::<(ConfigSetting, std::result::Result<(), String>), String>((original, result))
+        })
+        .await
+        .map_err(|e| format!("Task join error: {}", e))?;
+
+        let (original, trigger_result) = match triggered {
+            Ok(triggered) => triggered,
+            Err(e) => {
+                *self.active_sequence.lock().await = None;
+                return Err(e);
+            }
+        };
+
+        // Wait for the burst to land; each frame can take a while to write and transfer
+        let deadline = Instant::now() + Duration::from_secs(30) + Duration::from_secs(5) * shots;
+        let mut frames = 0;
+        if trigger_result.is_ok() {
+            while Instant::now() < deadline && !self.shutdown.load(Ordering::Relaxed) {
+                frames = self.active_sequence.lock().await.as_ref().map_or(0, |(_, frames)| *frames);
+                if frames >= shots {
+                    break;
+                }
+                tokio::time::sleep(Duration::from_millis(250)).await;
+            }
+        }
+        *self.active_sequence.lock().await = None;
+
+        // Leave the body shooting single frames again
+        let restore = tokio::task::spawn_blocking(move || Self::write_config_setting(&camera, &original.key, &original.value))
+            .await
+            .map_err(|e| format!("Task join error: {}", e))?;
+        if let Err(e) = restore {
+            eprintln!("{} [Camera] Failed to restore focus bracketing: {}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), e);
+        }
+
+        trigger_result?;
+        if frames < shots {
+            return Err(format!("Focus bracket incomplete: received {} of {} frames", frames, shots));
+        }
+        eprintln!("{} [Camera] Focus bracket complete: {} frames in {}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), frames, sequence_id);
+        Ok(sequence_id)
+    }
+
+    /// Mark a camera file as handled so the filesystem fallback doesn't download it again
+    fn remember_camera_file(&self, folder: &str, name: &str) -> bool {
+        self.known_camera_files.lock().unwrap().insert(format!("{}/{}", folder, name))
+    }
+
+    /// List every file on the camera's storage as (folder, name)
+    fn list_camera_files(camera: &Camera) -> std::result::Result<Vec<(String, String)>, String> {
+        let fs = camera.fs();
+        let mut files = Vec::new();
+        let mut folders = vec!["/".to_string()];
+        while let Some(folder) = folders.pop() {
+            let names = fs.list_files(&folder)
+                .wait()
+                .map_err(|e| format!("Failed to list '{}': {}", folder, e))?;
+            files.extend(names.map(|name| (folder.clone(), name.to_string())));
+
+            let subfolders = fs.list_folders(&folder)
+                .wait()
+                .map_err(|e| format!("Failed to list folders in '{}': {}", folder, e))?;
+            for subfolder in subfolders {
+                folders.push(format!("{}/{}", folder.trim_end_matches('/'), subfolder));
+            }
+        }
+        Ok(files)
+    }
+
+    /// List one folder on the card, folders first
+    /// Holds the card for the duration, pausing the event monitor's own card access.
+    pub async fn list_camera_folder(&self, folder: &str) -> std::result::Result<Vec<CardEntry>, String> {
+        let camera = self.connected_camera().await?;
+        let _io = self.camera_io_permit().await;
+        let folder = folder.to_string();
+        tokio::task::spawn_blocking(move || {
+            let fs = camera.fs();
+            let mut entries: Vec<CardEntry> = fs.list_folders(&folder)
+                .wait()
+                .map_err(|e| format!("Failed to list folders in '{}': {}", folder, e))?
+                .map(|name| CardEntry { name: name.to_string(), is_folder: true, size: None })
+                .collect();
+            let files: Vec<String> = fs.list_files(&folder)
+                .wait()
+                .map_err(|e| format!("Failed to list '{}': {}", folder, e))?
+                .map(|name| name.to_string())
+                .collect();
+            for name in files {
+                let size = Self::camera_file_size(&camera, &folder, &name);
+                entries.push(CardEntry { name, is_folder: false, size });
+            }
+            Ok(entries)
+        })
+        .await
+        .map_err(|e| format!("Task join error: {}", e))?
+    }
+
+    /// Get the card's folder tree below `root` ("/" when None), `max_depth` folder levels deep
+    /// A depth of 1 lists only the root's direct contents, so large cards can be expanded lazily.
+    pub async fn get_card_tree(&self, root: Option<String>, max_depth: u32) -> std::result::Result<CardNode, String> {
+        let camera = self.connected_camera().await?;
+        let _io = self.camera_io_permit().await;
+        let root = root.unwrap_or_else(|| "/".to_string());
+        tokio::task::spawn_blocking(move || {
+            let name = root.trim_end_matches('/').rsplit('/').next().unwrap_or_default().to_string();
+            let children = Self::read_card_folder(&camera, &root, max_depth)?;
+            Ok(CardNode { name, path: root, is_folder: true, size: None, children })
+        })
+        .await
+        .map_err(|e| format!("Task join error: {}", e))?
+    }
+
+    /// Contents of one card folder, recursing `depth` levels; None once the depth is used up
+    fn read_card_folder(camera: &Camera, folder: &str, depth: u32) -> std::result::Result<Option<Vec<CardNode>>, String> {
+        if depth == 0 {
+            return Ok(None);
+        }
+        let fs = camera.fs();
+        let subfolders: Vec<String> = fs.list_folders(folder)
+            .wait()
+            .map_err(|e| format!("Failed to list folders in '{}': {}", folder, e))?
+            .map(|name| name.to_string())
+            .collect();
+        let files: Vec<String> = fs.list_files(folder)
+            .wait()
+            .map_err(|e| format!("Failed to list '{}': {}", folder, e))?
+            .map(|name| name.to_string())
+            .collect();
+
+        let mut nodes = Vec::with_capacity(subfolders.len() + files.len());
+        for name in subfolders {
+            let path = format!("{}/{}", folder.trim_end_matches('/'), name);
+            let children = Self::read_card_folder(camera, &path, depth - 1)?;
+            nodes.push(CardNode { name, path, is_folder: true, size: None, children });
+        }
+        for name in files {
+            let path = format!("{}/{}", folder.trim_end_matches('/'), name);
+            let size = Self::camera_file_size(camera, folder, &name);
+            nodes.push(CardNode { name, path, is_folder: false, size, children: None });
+        }
+        Ok(Some(nodes))
+    }
+
+    /// Download one file from the card into `target_dir` under its original name
+    /// Holds the card like list_camera_folder; the file is marked known so the filesystem
+    /// fallback doesn't download it a second time.
+    pub async fn download_specific(&self, folder: &str, name: &str, target_dir: &str) -> std::result::Result<String, String> {
+        let camera = self.connected_camera().await?;
+        let verify = self.options.lock().await.verify_downloads;
+        let _io = self.camera_io_permit().await;
+        let (source_folder, source_name) = (folder.to_string(), name.to_string());
+        let target_dir = PathBuf::from(target_dir);
+        let file_path = tokio::task::spawn_blocking(move || {
+            std::fs::create_dir_all(&target_dir)
+                .map_err(|e| format!("Failed to create target directory: {}", e))?;
+            let file_path = target_dir.join(&source_name);
+            Self::download_verified(&camera, &source_folder, &source_name, &file_path, verify)?;
+            Ok::<PathBuf, String>(file_path)
+        })
+        .await
+        .map_err(|e| format!("Task join error: {}", e))??;
+
+        self.remember_camera_file(folder, name);
+        Ok(file_path.to_string_lossy().to_string())
+    }
+
+    /// Number of a DCIM folder ("101CANON" -> 101); None for other folders
+    fn dcim_folder_number(name: &str) -> Option<u32> {
+        let digits: String = name.chars().take_while(|c| c.is_ascii_digit()).collect();
+        if digits.len() == 3 { digits.parse().ok() } else { None }
+    }
+
+    /// Collect the files with the highest frame counter in the newest DCIM folder under `folder`
+    /// Only the highest numbered DCIM folder that holds files is listed; other folders (storages,
+    /// DCIM itself) are walked. Files without a frame counter are all kept.
+    fn collect_newest_files(camera: &Camera, folder: &str, candidates: &mut Vec<(String, String)>) -> std::result::Result<(), String> {
+        let fs = camera.fs();
+        let names: Vec<String> = fs.list_files(folder)
+            .wait()
+            .map_err(|e| format!("Failed to list '{}': {}", folder, e))?
+            .map(|name| name.to_string())
+            .collect();
+        let counter = |name: &str| Self::extract_sequence_number(name).and_then(|digits| digits.parse::<u32>().ok());
+        let top = names.iter().filter_map(|name| counter(name)).max();
+        candidates.extend(
+            names
+                .into_iter()
+                .filter(|name| top.is_none() || counter(name) == top)
+                .map(|name| (folder.to_string(), name)),
+        );
+
+        let mut subfolders: Vec<String> = fs.list_folders(folder)
+            .wait()
+            .map_err(|e| format!("Failed to list folders in '{}': {}", folder, e))?
+            .map(|name| name.to_string())
+            .collect();
+        subfolders.sort_by_key(|name| std::cmp::Reverse((Self::dcim_folder_number(name), name.clone())));
+        let mut numbered_found = false;
+        for subfolder in subfolders {
+            let numbered = Self::dcim_folder_number(&subfolder).is_some();
+            if numbered && numbered_found {
+                continue;
+            }
+            let before = candidates.len();
+            Self::collect_newest_files(camera, &format!("{}/{}", folder.trim_end_matches('/'), subfolder), candidates)?;
+            numbered_found |= numbered && candidates.len() > before;
+        }
+        Ok(())
+    }
+
+    /// Newest file on the card: the last frame in the highest DCIM folder
+    /// Modification times are only read when that order is ambiguous (two cards, files without a
+    /// frame counter), and then only for the few candidates; a RAW+JPEG pair counts as one shot.
+    fn newest_camera_file(camera: &Camera) -> std::result::Result<(String, String), String> {
+        let mut candidates = Vec::new();
+        Self::collect_newest_files(camera, "/", &mut candidates)?;
+
+        let stem = |name: &str| name.split('.').next().unwrap_or(name).to_string();
+        let one_shot = candidates.windows(2).all(|pair| pair[0].0 == pair[1].0 && stem(&pair[0].1) == stem(&pair[1].1));
+        if one_shot {
+            return candidates
+                .into_iter()
+                .max()
+                .ok_or_else(|| "No files on the camera's card".to_string());
+        }
+
+        let fs = camera.fs();
+        candidates
+            .into_iter()
+            .max_by_key(|(folder, name)| {
+                let mtime = fs.file_info(folder, name)
+                    .wait()
+                    .ok()
+                    .and_then(|info| info.file())
+                    .and_then(|file| file.mtime());
+                (mtime, folder.clone(), name.clone())
+            })
+            .ok_or_else(|| "No files on the camera's card".to_string())
+    }
+
+    /// Fetch the small thumbnail the camera keeps for a card file; None if it has none
+    fn read_camera_thumbnail(camera: &Camera, folder: &str, name: &str) -> Option<Vec<u8>> {
+        let context = Self::new_context().ok()?;
+        let file = camera.fs().download_preview(folder, name).wait().ok()?;
+        let data = file.get_data(&context).wait().ok()?;
+        (!data.is_empty()).then(|| data.to_vec())
+    }
+
+    /// Emit a card file's thumbnail on `camera:thumbnail` as a JPEG data URL
+    fn emit_camera_thumbnail(app: &AppHandle, folder: &str, name: &str, thumbnail: &[u8]) {
+        app.emit("camera:thumbnail", serde_json::json!({
+            "folder": folder,
+            "name": name,
+            "data": format!("data:image/jpeg;base64,{}", general_purpose::STANDARD.encode(thumbnail)),
+        })).ok();
+    }
+
+    /// Fetch and emit the in-camera thumbnail of the newest file on the card, without downloading it
+    pub async fn get_last_thumbnail(&self, app: AppHandle) -> std::result::Result<Option<Vec<u8>>, String> {
+        let camera = self.connected_camera().await?;
+        let _io = self.camera_io_permit().await;
+        let (folder, name, thumbnail) = tokio::task::spawn_blocking(move || {
+            let (folder, name) = Self::newest_camera_file(&camera)?;
+            let thumbnail = Self::read_camera_thumbnail(&camera, &folder, &name);
+            Ok::<(String, String, Option<Vec<u8>>), String>((folder, name, thumbnail))
+        })
+        .await
+        .map_err(|e| format!("Task join error: {}", e))??;
+
+        if let Some(thumbnail) = &thumbnail {
+            Self::emit_camera_thumbnail(&app, &folder, &name, thumbnail);
+        }
+        Ok(thumbnail)
+    }
+
+    /// Download the newest file on the card, whether the app or the body took it
+    pub async fn download_latest(&self, app: AppHandle, target_folder: Option<String>) -> std::result::Result<CaptureResult, String> {
+        let camera = self.connected_camera().await?;
+        let capture_dir = match target_folder {
+            Some(folder) => PathBuf::from(folder),
+            None => self.capture_dir.clone(),
+        };
+
+        let result = {
+            let _io = self.camera_io_permit().await;
+            let list_camera = camera.clone();
+            let (folder, name) = tokio::task::spawn_blocking(move || Self::newest_camera_file(&list_camera))
+                .await
+                .map_err(|e| format!("Task join error: {}", e))??;
+
+            eprintln!("{} [Camera] Downloading latest card file {}/{}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), folder, name);
+            self.remember_camera_file(&folder, &name);
+            self.download_camera_file(&app, camera, folder, name, capture_dir).await?
+        };
+
+        self.emit_captured(&app, &result).await;
+        Ok(result)
+    }
+
+    /// Download a camera-button capture into the current download folder in the background
+    fn spawn_button_download(self: &Arc<Self>, app: &AppHandle, camera: Camera, folder: String, name: String) {
+        let this = self.clone();
+        let app = app.clone();
+        tokio::spawn(async move {
+            if this.card_only.load(Ordering::Relaxed) {
+                eprintln!("{} [Camera] Capture destination is card only, leaving {}/{} on the card", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), folder, name);
+                return;
+            }
+            let _io = this.camera_io_permit().await;
+            if let Some(max_size) = this.options.lock().await.max_auto_download_size {
+                let (size_camera, size_folder, size_name) = (camera.clone(), folder.clone(), name.clone());
+                let size = tokio::task::spawn_blocking(move || Self::camera_file_size(&size_camera, &size_folder, &size_name))
+                    .await
+                    .ok()
+                    .flatten();
+                if let Some(size) = size.filter(|size| *size > max_size) {
+                    eprintln!("{} [Camera] Leaving {}/{} on the card: {} bytes is over the {} byte limit", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), folder, name, size, max_size);
+                    app.emit("camera:large_file_skipped", serde_json::json!({
+                        "folder": folder,
+                        "name": name,
+                        "size": size,
+                    })).ok();
+                    return;
+                }
+            }
+
+            // Show the shot right away; the full file can take seconds for a large RAW
+            let (thumb_camera, thumb_folder, thumb_name) = (camera.clone(), folder.clone(), name.clone());
+            let thumbnail = tokio::task::spawn_blocking(move || Self::read_camera_thumbnail(&thumb_camera, &thumb_folder, &thumb_name))
+                .await
+                .ok()
+                .flatten();
+            if let Some(thumbnail) = thumbnail {
+                Self::emit_camera_thumbnail(&app, &folder, &name, &thumbnail);
+            }
+
+            let download_folder = this.current_download_folder.lock().await.clone();
+            let capture_dir = if let Some(folder) = download_folder {
+                std::path::PathBuf::from(folder)
+            } else {
+                this.capture_dir.clone()
+            };
+
+            if let Ok(result) = this.download_camera_file(&app, camera, folder, name, capture_dir).await {
+                this.emit_captured(&app, &result).await;
+            }
+        });
+    }
+
+    /// Diff the card against the known files and download what's new
+    /// With `prime`, the current contents are only recorded, so old shots on the card are skipped.
+    async fn poll_camera_files(self: &Arc<Self>, app: &AppHandle, camera: Camera, prime: bool) -> std::result::Result<(), String> {
+        let list_camera = camera.clone();
+        let files = tokio::task::spawn_blocking(move || Self::list_camera_files(&list_camera))
+            .await
+            .map_err(|e| format!("Task join error: {}", e))??;
+
+        for (folder, name) in files {
+            if self.remember_camera_file(&folder, &name) && !prime {
+                eprintln!("{} [Camera] New file found by polling: {}/{}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), folder, name);
+                self.spawn_button_download(app, camera.clone(), folder, name);
+            }
+        }
+        Ok(())
     }
 
     /// Start monitoring camera events (for camera button captures)
     pub fn start_event_monitoring(self: Arc<Self>, app: AppHandle) {
-        tokio::spawn(async move {
+        let this = self.clone();
+        let handle = tokio::spawn(async move {
             self.start_event_monitoring_inner(app.clone(), None).await;
         });
+        this.track_task(handle);
     }
 
     /// Start monitoring camera events with a flag that can be used for reconnection tracking
@@ -650,12 +6575,37 @@ impl CameraService {
         self.start_event_monitoring_inner(app.clone(), Some(active_flag)).await;
     }
 
+    /// Check a lowercased gphoto2 error for the PTP/USB failures that mean the body is gone
+    /// "Unspecified error" (0x2002) often happens when camera is disconnected;
+    /// "Could not find the requested device on the USB port" indicates USB disconnect
+    fn is_disconnect_error(error_msg: &str) -> bool {
+        error_msg.contains("no device")
+            || error_msg.contains("not found")
+            || error_msg.contains("disconnected")
+            || error_msg.contains("i/o error")
+            || error_msg.contains("unspecified")
+            || error_msg.contains("general error")
+            || error_msg.contains("usb port")
+    }
+
     /// Inner event monitoring implementation
     async fn start_event_monitoring_inner(self: Arc<Self>, app: AppHandle, active_flag: Option<Arc<std::sync::atomic::AtomicBool>>) {
         let mut event_interval = tokio::time::interval(Duration::from_millis(100));
+        // Bodies that never report NewFile get their button captures found by listing the card
+        let mut consecutive_timeouts: u32 = 0;
+        let mut new_file_seen = false;
+        let mut fs_polling = false;
+        let mut last_fs_poll = Instant::now();
         loop {
             event_interval.tick().await;
 
+            if self.shutdown.load(Ordering::Relaxed) {
+                if let Some(flag) = active_flag {
+                    flag.store(false, Ordering::Relaxed);
+                }
+                break;
+            }
+
             // Check if camera is connected
             let camera_opt = {
                 let guard = self.camera.lock().await;
@@ -663,14 +6613,22 @@ impl CameraService {
             };
 
             if let Some(camera) = camera_opt {
+                // Wait out downloads and card browsing instead of colliding with their transfers
+                let io_permit = self.camera_io.clone().acquire_owned().await.ok();
+
                 // Clone camera for use in event monitoring
                 let camera_clone = camera.clone();
+                let event_wait = if self.liveview_active.load(Ordering::Relaxed) {
+                    LIVEVIEW_EVENT_WAIT
+                } else {
+                    Duration::from_millis(300)
+                };
 
                 // Check for events - wrapped in catch_unwind to handle gphoto2 crashes
                 let event_result = tokio::task::spawn_blocking(move || {
                     // Wrap in catch_unwind to recover from gphoto2 library crashes
                     std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-                        camera_clone.wait_event(Duration::from_millis(300)).wait()
+                        camera_clone.wait_event(event_wait).wait()
                     }))
                 })
                 .await;
@@ -682,17 +6640,12 @@ impl CameraService {
                         // gphoto2 returned an error
                         let error_msg = e.to_string().to_lowercase();
 
-                        // Check if camera is disconnected
-                        // "Unspecified error" (0x2002) often happens when camera is disconnected
-                        // "Could not find the requested device on the USB port" indicates USB disconnect
-                        if error_msg.contains("no device")
-                            || error_msg.contains("not found")
-                            || error_msg.contains("disconnected")
-                            || error_msg.contains("i/o error")
-                            || error_msg.contains("unspecified")
-                            || error_msg.contains("general error")
-                            || error_msg.contains("usb port") {
+                        // Check if camera is disconnected; over PTP/IP a timeout means the link dropped,
+                        // and auto-connect then retries the same address
+                        let network = self.active_port.lock().await.as_deref().map_or(false, |port| port.starts_with(PTPIP_PORT_PREFIX));
+                        if Self::is_disconnect_error(&error_msg) || (network && error_msg.contains("timeout")) {
                             eprintln!("{} [Camera] Disconnected", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"));
+                            self.record_connection_event(&app, false, ConnectionReason::from_error(&error_msg), Some(e.to_string())).await;
                             // Clear camera and emit disconnect event
                             {
                                 let mut camera_guard = self.camera.lock().await;
@@ -712,6 +6665,7 @@ impl CameraService {
                     Ok(Err(_panic_info)) => {
                         // A panic occurred in the wait_event call (likely gphoto2 segfault)
                         eprintln!("{} [Camera] Thread panic - disconnected", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"));
+                        self.record_connection_event(&app, false, ConnectionReason::Panic, None).await;
                         // Clear camera and emit disconnect event
                         {
                             let mut camera_guard = self.camera.lock().await;
@@ -737,94 +6691,515 @@ impl CameraService {
                     }
                 };
 
-                if let Some(event) = event {
-                    match event {
-                        CameraEvent::NewFile(file_path) => {
-                            // Get current download folder
-                            let download_folder = self.current_download_folder.lock().await.clone();
-                            let capture_dir = if let Some(folder) = download_folder {
-                                std::path::PathBuf::from(folder)
-                            } else {
-                                self.capture_dir.clone()
-                            };
+                if let Some(event) = event {
+                    match event {
+                        CameraEvent::NewFile(file_path) => {
+                            // The body reports new files, polling is not needed
+                            new_file_seen = true;
+                            fs_polling = false;
+                            consecutive_timeouts = 0;
+
+                            let folder_str = file_path.folder().to_string();
+                            let name_str = file_path.name().to_string();
+                            self.remember_camera_file(&folder_str, &name_str);
+
+                            // Spawn background download task
+                            self.spawn_button_download(&app, camera.clone(), folder_str, name_str);
+                        }
+                        CameraEvent::CaptureComplete => {}
+                        CameraEvent::Timeout => {
+                            consecutive_timeouts = consecutive_timeouts.saturating_add(1);
+                            if !new_file_seen
+                                && !fs_polling
+                                && consecutive_timeouts >= EVENT_TIMEOUTS_BEFORE_FS_POLL
+                                && self.options.lock().await.filesystem_poll_fallback
+                            {
+                                match self.poll_camera_files(&app, camera.clone(), true).await {
+                                    Ok(()) => {
+                                        eprintln!("{} [Camera] No NewFile events after {} timeouts, polling the card instead", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), consecutive_timeouts);
+                                        fs_polling = true;
+                                        last_fs_poll = Instant::now();
+                                    }
+                                    Err(e) => {
+                                        eprintln!("{} [Camera] Filesystem fallback unavailable: {}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), e);
+                                        consecutive_timeouts = 0;
+                                    }
+                                }
+                            }
+                        }
+                        CameraEvent::Unknown(message) => {
+                            // PTP property change events (dial turned on the body)
+                            if message.to_lowercase().contains("property") {
+                                *self.cached_params.lock().await = None;
+                                self.params_dirty.store(true, Ordering::Relaxed);
+                            }
+                        }
+                        CameraEvent::FileChanged(_) => {}
+                        CameraEvent::NewFolder(_) => {}
+                    }
+                }
+
+                // Don't list the card while a capture or live view owns the camera
+                let camera_idle = self.active_operation.lock().unwrap().is_none()
+                    && !self.liveview_active.load(Ordering::Relaxed);
+                if fs_polling && camera_idle && last_fs_poll.elapsed() >= FS_POLL_INTERVAL {
+                    last_fs_poll = Instant::now();
+                    if let Err(e) = self.poll_camera_files(&app, camera, false).await {
+                        eprintln!("{} [Camera] Filesystem poll failed: {}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), e);
+                    }
+                }
+                drop(io_permit);
+            } else {
+                // Camera disconnected, clear flag and exit
+                if let Some(flag) = active_flag {
+                    flag.store(false, std::sync::atomic::Ordering::Relaxed);
+                }
+                break;
+            }
+        }
+    }
+}
+
+// ============================================================================
+// Tauri Commands
+// ============================================================================
+
+/// Connect to a camera, optionally the one on a port from tether_list_cameras
+#[tauri::command]
+pub async fn tether_connect(
+    service: tauri::State<'_, CameraService>,
+    app: AppHandle,
+    port: Option<String>,
+) -> std::result::Result<CameraParams, String> {
+    service.connect_camera(app, port).await
+}
+
+/// Connect to a network (PTP/IP) camera by IP address
+#[tauri::command]
+pub async fn tether_connect_network(
+    service: tauri::State<'_, CameraService>,
+    app: AppHandle,
+    ip: String,
+) -> std::result::Result<CameraParams, String> {
+    service.connect_network_camera(app, ip).await
+}
+
+/// List attached cameras (model and port) for a camera picker
+#[tauri::command]
+pub async fn tether_list_cameras(
+    service: tauri::State<'_, CameraService>,
+) -> std::result::Result<Vec<DeviceInfo>, String> {
+    service.list_cameras().await
+}
+
+/// Disconnect from camera
+#[tauri::command]
+pub async fn tether_disconnect(
+    service: tauri::State<'_, CameraService>,
+    app: AppHandle,
+) -> std::result::Result<(), String> {
+    service.disconnect_camera(app).await
+}
+
+/// Get current camera parameters
+/// Pass `force` to bypass the params cache and re-read from the camera
+#[tauri::command]
+pub async fn tether_get_params(
+    service: tauri::State<'_, CameraService>,
+    force: Option<bool>,
+) -> std::result::Result<CameraParams, String> {
+    service.get_camera_params(force.unwrap_or(false)).await
+}
+
+/// Capture a photo
+#[tauri::command]
+pub async fn tether_capture(
+    service: tauri::State<'_, CameraService>,
+    app: AppHandle,
+    target_folder: Option<String>,
+    options: Option<CaptureOptions>,
+) -> std::result::Result<CaptureResult, String> {
+    service.capture_and_download(app, target_folder, options.unwrap_or_default()).await
+}
+
+/// Shoot a set of dark, flat or bias calibration frames
+#[tauri::command]
+pub async fn tether_capture_calibration_set(
+    service: tauri::State<'_, CameraService>,
+    app: AppHandle,
+    target_folder: Option<String>,
+    kind: CalibrationKind,
+    count: u32,
+) -> std::result::Result<Vec<CaptureResult>, String> {
+    service.capture_calibration_set(app, target_folder, kind, count).await
+}
+
+/// Shoot one frame per white balance value and restore the original afterwards
+#[tauri::command]
+pub async fn tether_capture_wb_bracket(
+    service: tauri::State<'_, CameraService>,
+    app: AppHandle,
+    target_folder: Option<String>,
+    values: Vec<String>,
+    image_format: Option<String>,
+) -> std::result::Result<Vec<CaptureResult>, String> {
+    service.capture_wb_bracket(app, target_folder, values, image_format).await
+}
+
+/// Shoot a 3, 5 or 7 frame exposure bracket around a center EV
+#[tauri::command]
+pub async fn tether_capture_bracket(
+    service: tauri::State<'_, CameraService>,
+    app: AppHandle,
+    target_folder: Option<String>,
+    center_ev: f32,
+    step_ev: f32,
+    frames: u32,
+    image_format: Option<String>,
+) -> std::result::Result<Vec<CaptureResult>, String> {
+    service.capture_bracket(app, target_folder, center_ev, step_ev, frames, image_format).await
+}
+
+/// Shoot one frame per ISO value for noise testing, restoring the original ISO afterwards
+#[tauri::command]
+pub async fn tether_capture_iso_sweep(
+    service: tauri::State<'_, CameraService>,
+    app: AppHandle,
+    target_folder: Option<String>,
+    isos: Vec<String>,
+    image_format: Option<String>,
+) -> std::result::Result<Vec<CaptureResult>, String> {
+    service.capture_iso_sweep(app, target_folder, isos, image_format).await
+}
+
+/// Adjust exposure compensation toward a target preview brightness, then capture
+#[tauri::command]
+pub async fn tether_capture_auto_exposure(
+    service: tauri::State<'_, CameraService>,
+    app: AppHandle,
+    target_folder: Option<String>,
+    target_mean: f32,
+) -> std::result::Result<AutoExposureResult, String> {
+    service.capture_auto_exposure(app, target_folder, target_mean).await
+}
+
+/// List attached cameras with model and port, without connecting to them
+#[tauri::command]
+pub async fn tether_enumerate_devices(
+    service: tauri::State<'_, CameraService>,
+) -> std::result::Result<Vec<DeviceInfo>, String> {
+    service.enumerate_devices().await
+}
+
+/// Shoot into a temp area and return a preview to confirm or discard
+#[tauri::command]
+pub async fn tether_capture_pending(
+    service: tauri::State<'_, CameraService>,
+    app: AppHandle,
+    target_folder: Option<String>,
+) -> std::result::Result<PendingCapture, String> {
+    service.capture_pending(app, target_folder).await
+}
+
+/// Keep a pending capture, moving it into the download folder
+#[tauri::command]
+pub async fn tether_confirm_capture(
+    service: tauri::State<'_, CameraService>,
+    app: AppHandle,
+    pending_id: String,
+) -> std::result::Result<CaptureResult, String> {
+    service.confirm_capture(app, &pending_id).await
+}
+
+/// Delete a pending capture
+#[tauri::command]
+pub async fn tether_discard_capture(
+    service: tauri::State<'_, CameraService>,
+    pending_id: String,
+) -> std::result::Result<(), String> {
+    service.discard_capture(&pending_id)
+}
+
+/// Start streaming live view frames on `camera:preview`
+#[tauri::command]
+pub async fn tether_start_liveview(
+    service: tauri::State<'_, CameraService>,
+    app: AppHandle,
+    fps: Option<f32>,
+) -> std::result::Result<(), String> {
+    service.shared().start_liveview(app, fps).await
+}
+
+/// Stop the live view stream
+#[tauri::command]
+pub async fn tether_stop_liveview(
+    service: tauri::State<'_, CameraService>,
+) -> std::result::Result<(), String> {
+    service.stop_liveview();
+    Ok(())
+}
+
+/// Step the lens focus near or far while live view runs
+#[tauri::command]
+pub async fn tether_drive_focus(
+    service: tauri::State<'_, CameraService>,
+    direction: FocusDirection,
+    step: FocusStep,
+) -> std::result::Result<(), String> {
+    service.drive_focus(direction, step).await
+}
+
+/// Shoot a focus stack by stepping manual focus drive between frames
+#[tauri::command]
+pub async fn tether_capture_focus_stack(
+    service: tauri::State<'_, CameraService>,
+    app: AppHandle,
+    target_folder: Option<String>,
+    frames: u32,
+    direction: FocusDirection,
+    step: FocusStep,
+    image_format: Option<String>,
+) -> std::result::Result<Vec<CaptureResult>, String> {
+    service.capture_focus_stack(app, target_folder, frames, direction, step, image_format).await
+}
+
+/// Fire one frame immediately, e.g. from an external trigger
+#[tauri::command]
+pub async fn tether_trigger_capture(
+    service: tauri::State<'_, CameraService>,
+    app: AppHandle,
+) -> std::result::Result<CaptureResult, String> {
+    service.trigger_capture(app).await
+}
+
+/// Fire the shutter and return a capture id; the download finishes in the background
+#[tauri::command]
+pub async fn tether_capture_async(
+    service: tauri::State<'_, CameraService>,
+    app: AppHandle,
+    target_folder: Option<String>,
+) -> std::result::Result<String, String> {
+    service.shared().capture_async(app, target_folder).await
+}
+
+/// Shoot an in-camera focus bracket, returning the sequence id shared by its frames
+#[tauri::command]
+pub async fn tether_capture_native_focus_bracket(
+    service: tauri::State<'_, CameraService>,
+    shots: u32,
+    step: u32,
+) -> std::result::Result<String, String> {
+    service.capture_native_focus_bracket(shots, step).await
+}
+
+/// Get the camera's current orientation, if the body reports it
+#[tauri::command]
+pub async fn tether_get_orientation(
+    service: tauri::State<'_, CameraService>,
+) -> std::result::Result<Option<Orientation>, String> {
+    service.get_orientation().await
+}
+
+/// Get the body's image review and auto-rotate settings with their choices
+#[tauri::command]
+pub async fn tether_get_review_settings(
+    service: tauri::State<'_, CameraService>,
+) -> std::result::Result<ReviewSettings, String> {
+    service.get_review_settings().await
+}
+
+/// Set the body's image review duration
+#[tauri::command]
+pub async fn tether_set_image_review(
+    service: tauri::State<'_, CameraService>,
+    value: String,
+) -> std::result::Result<(), String> {
+    service.set_image_review(&value).await
+}
+
+/// Set the body's auto-rotate
+#[tauri::command]
+pub async fn tether_set_auto_rotate(
+    service: tauri::State<'_, CameraService>,
+    value: String,
+) -> std::result::Result<(), String> {
+    service.set_auto_rotate(&value).await
+}
+
+/// Get the body's exposure delay and its choices
+#[tauri::command]
+pub async fn tether_get_exposure_delay(
+    service: tauri::State<'_, CameraService>,
+) -> std::result::Result<Option<ExposureDelay>, String> {
+    service.get_exposure_delay().await
+}
+
+/// Set the body's exposure delay
+#[tauri::command]
+pub async fn tether_set_exposure_delay(
+    service: tauri::State<'_, CameraService>,
+    value: String,
+) -> std::result::Result<(), String> {
+    service.set_exposure_delay(&value).await
+}
+
+/// Turn the depth-of-field preview on or off
+#[tauri::command]
+pub async fn tether_dof_preview(
+    service: tauri::State<'_, CameraService>,
+    enable: bool,
+) -> std::result::Result<(), String> {
+    service.dof_preview(enable).await
+}
+
+/// Get the body's focus beep and AF-assist lamp settings with their choices
+#[tauri::command]
+pub async fn tether_get_quiet_settings(
+    service: tauri::State<'_, CameraService>,
+) -> std::result::Result<QuietSettings, String> {
+    service.get_quiet_settings().await
+}
+
+/// Set the body's focus beep
+#[tauri::command]
+pub async fn tether_set_beep(
+    service: tauri::State<'_, CameraService>,
+    value: String,
+) -> std::result::Result<(), String> {
+    service.set_beep(&value).await
+}
+
+/// Set the body's AF-assist lamp
+#[tauri::command]
+pub async fn tether_set_af_assist(
+    service: tauri::State<'_, CameraService>,
+    value: String,
+) -> std::result::Result<(), String> {
+    service.set_af_assist(&value).await
+}
+
+/// Get drive, AF and metering mode choices with their current values
+#[tauri::command]
+pub async fn tether_get_mode_choices(
+    service: tauri::State<'_, CameraService>,
+) -> std::result::Result<ModeChoices, String> {
+    service.get_mode_choices().await
+}
+
+/// Switch between mechanical, electronic first curtain and silent electronic shutter
+#[tauri::command]
+pub async fn tether_set_shutter_type(
+    service: tauri::State<'_, CameraService>,
+    shutter_type: ShutterType,
+) -> std::result::Result<(), String> {
+    service.set_shutter_type(shutter_type).await
+}
+
+/// Fire a number of test captures and report capture/download timings and throughput
+#[tauri::command]
+pub async fn tether_benchmark_capture(
+    service: tauri::State<'_, CameraService>,
+    count: u32,
+) -> std::result::Result<BenchmarkReport, String> {
+    service.benchmark_capture(count).await
+}
+
+/// Connect/disconnect events since startup, for diagnosing unstable connections
+#[tauri::command]
+pub async fn tether_get_connection_history(
+    service: tauri::State<'_, CameraService>,
+) -> std::result::Result<Vec<ConnectionEvent>, String> {
+    Ok(service.get_connection_history())
+}
+
+/// Enable WebP/AVIF remote previews with a byte budget for this session, or disable them with null
+#[tauri::command]
+pub async fn tether_set_remote_preview(
+    service: tauri::State<'_, CameraService>,
+    settings: Option<RemotePreviewSettings>,
+) -> std::result::Result<(), String> {
+    service.set_remote_preview(settings).await
+}
+
+/// Captures emitted recently, for a frontend catching up after a reload
+#[tauri::command]
+pub async fn tether_get_recent_captures(
+    service: tauri::State<'_, CameraService>,
+) -> std::result::Result<Vec<CaptureResult>, String> {
+    Ok(service.get_recent_captures())
+}
 
-                            let folder_str = file_path.folder().to_string();
-                            let name_str = file_path.name().to_string();
+/// Get the in-camera aspect ratio and its choices
+#[tauri::command]
+pub async fn tether_get_aspect_ratio(
+    service: tauri::State<'_, CameraService>,
+) -> std::result::Result<Option<ConfigSetting>, String> {
+    service.get_aspect_ratio().await
+}
 
-                            // Spawn background download task
-                            let self_clone = self.clone();
-                            let app_clone = app.clone();
-                            tokio::spawn(async move {
-                                if let Ok((file_path, width, height)) = self_clone.download_camera_file(
-                                    camera,
-                                    folder_str,
-                                    name_str,
-                                    capture_dir,
-                                ).await {
-                                    app_clone.emit("camera:captured", serde_json::json!({
-                                        "filePath": file_path,
-                                        "width": width,
-                                        "height": height,
-                                    })).ok();
-                                }
-                            });
-                        }
-                        CameraEvent::CaptureComplete => {}
-                        CameraEvent::Timeout => {}
-                        CameraEvent::Unknown(_) => {}
-                        CameraEvent::FileChanged(_) => {}
-                        CameraEvent::NewFolder(_) => {}
-                    }
-                }
-            } else {
-                // Camera disconnected, clear flag and exit
-                if let Some(flag) = active_flag {
-                    flag.store(false, std::sync::atomic::Ordering::Relaxed);
-                }
-                break;
-            }
-        }
-    }
+/// Set the in-camera aspect ratio
+#[tauri::command]
+pub async fn tether_set_aspect_ratio(
+    service: tauri::State<'_, CameraService>,
+    value: String,
+) -> std::result::Result<(), String> {
+    service.set_aspect_ratio(&value).await
 }
 
-// ============================================================================
-// Tauri Commands
-// ============================================================================
+/// Get the attached lens and its aperture/focal range
+#[tauri::command]
+pub async fn tether_get_lens_info(
+    service: tauri::State<'_, CameraService>,
+) -> std::result::Result<Option<LensInfo>, String> {
+    service.get_lens_info().await
+}
 
-/// Connect to a camera
+/// Get the normalized exposure program (manual, aperture, shutter, ...)
 #[tauri::command]
-pub async fn tether_connect(
+pub async fn tether_get_exposure_program(
     service: tauri::State<'_, CameraService>,
-    app: AppHandle,
-) -> std::result::Result<CameraParams, String> {
-    service.connect_camera(app).await
+) -> std::result::Result<Option<ExposureProgram>, String> {
+    service.get_exposure_program().await
 }
 
-/// Disconnect from camera
+/// Get the manual-mode exposure meter reading in EV
 #[tauri::command]
-pub async fn tether_disconnect(
+pub async fn tether_get_exposure_meter(
+    service: tauri::State<'_, CameraService>,
+) -> std::result::Result<Option<f32>, String> {
+    service.get_exposure_meter().await
+}
+
+/// Get what the connected camera supports (live view, bulb, video, remote AF, ...)
+#[tauri::command]
+pub async fn tether_get_capabilities(
+    service: tauri::State<'_, CameraService>,
+) -> std::result::Result<CameraCapabilities, String> {
+    service.get_capabilities().await
+}
+
+/// Autofocus and hold focus for a following capture
+#[tauri::command]
+pub async fn tether_lock_focus(
     service: tauri::State<'_, CameraService>,
-    app: AppHandle,
 ) -> std::result::Result<(), String> {
-    service.disconnect_camera(app).await
+    service.lock_focus().await
 }
 
-/// Get current camera parameters
+/// Run autofocus once and wait for it to lock
 #[tauri::command]
-pub async fn tether_get_params(
+pub async fn tether_autofocus(
     service: tauri::State<'_, CameraService>,
-) -> std::result::Result<CameraParams, String> {
-    service.get_camera_params().await
+) -> std::result::Result<(), String> {
+    service.autofocus().await
 }
 
-/// Capture a photo
+/// Release a held focus lock
 #[tauri::command]
-pub async fn tether_capture(
+pub async fn tether_unlock_focus(
     service: tauri::State<'_, CameraService>,
-    app: AppHandle,
-    target_folder: Option<String>,
-) -> std::result::Result<CaptureResult, String> {
-    service.capture_and_download(app, target_folder).await
+) -> std::result::Result<(), String> {
+    service.unlock_focus().await
 }
 
 /// Start background monitoring
@@ -834,12 +7209,7 @@ pub async fn tether_start_monitoring(
     app: AppHandle,
 ) -> std::result::Result<(), String> {
     // Create a new Arc wrapper that shares the same inner state
-    let service_arc = Arc::new(CameraService {
-        camera: service.camera.clone(),
-        capture_dir: service.capture_dir.clone(),
-        current_download_folder: service.current_download_folder.clone(),
-        cached_dimensions: service.cached_dimensions.clone(),
-    });
+    let service_arc = service.shared();
 
     // Start both connection monitoring and event monitoring
     service_arc.clone().start_monitoring(app.clone()).await?;
@@ -848,6 +7218,200 @@ pub async fn tether_start_monitoring(
     Ok(())
 }
 
+/// Stop all background camera tasks, e.g. to release the camera to another app
+#[tauri::command]
+pub async fn tether_stop_all(
+    service: tauri::State<'_, CameraService>,
+) -> std::result::Result<(), String> {
+    service.stop_all().await
+}
+
+/// Stop the monitor from retrying auto-connect until the next manual connect
+#[tauri::command]
+pub async fn tether_cancel_auto_connect(
+    service: tauri::State<'_, CameraService>,
+) -> std::result::Result<(), String> {
+    service.cancel_auto_connect();
+    Ok(())
+}
+
+/// List one folder on the camera's card
+#[tauri::command]
+pub async fn tether_list_camera_folder(
+    service: tauri::State<'_, CameraService>,
+    folder: String,
+) -> std::result::Result<Vec<CardEntry>, String> {
+    service.list_camera_folder(&folder).await
+}
+
+/// Check that iso, shutter speed and aperture resolve on the connected body, with candidate keys if not
+#[tauri::command]
+pub async fn tether_verify_param_aliases(
+    service: tauri::State<'_, CameraService>,
+) -> std::result::Result<AliasReport, String> {
+    service.verify_param_aliases().await
+}
+
+/// Fetch the in-camera thumbnail of the newest card file without downloading the file
+#[tauri::command]
+pub async fn tether_get_last_thumbnail(
+    service: tauri::State<'_, CameraService>,
+    app: AppHandle,
+) -> std::result::Result<Option<Vec<u8>>, String> {
+    service.get_last_thumbnail(app).await
+}
+
+/// Download the newest file on the camera's card
+#[tauri::command]
+pub async fn tether_download_latest(
+    service: tauri::State<'_, CameraService>,
+    app: AppHandle,
+    target_folder: Option<String>,
+) -> std::result::Result<CaptureResult, String> {
+    service.download_latest(app, target_folder).await
+}
+
+/// Get the card's folder tree below a folder, up to `max_depth` levels
+#[tauri::command]
+pub async fn tether_get_card_tree(
+    service: tauri::State<'_, CameraService>,
+    root: Option<String>,
+    max_depth: u32,
+) -> std::result::Result<CardNode, String> {
+    service.get_card_tree(root, max_depth).await
+}
+
+/// Download one file from the camera's card into a folder, returning the local path
+#[tauri::command]
+pub async fn tether_download_specific(
+    service: tauri::State<'_, CameraService>,
+    folder: String,
+    name: String,
+    target_dir: String,
+) -> std::result::Result<String, String> {
+    service.download_specific(&folder, &name, &target_dir).await
+}
+
+/// List the captures already in the current download/session folder, oldest first
+#[tauri::command]
+pub async fn tether_list_session_captures(
+    service: tauri::State<'_, CameraService>,
+) -> std::result::Result<Vec<CaptureResult>, String> {
+    service.list_session_captures().await
+}
+
+/// Start a named capture session, returning its id
+#[tauri::command]
+pub async fn tether_start_session(
+    service: tauri::State<'_, CameraService>,
+    name: String,
+) -> std::result::Result<String, String> {
+    service.start_session(&name).await
+}
+
+/// End the active capture session
+#[tauri::command]
+pub async fn tether_end_session(
+    service: tauri::State<'_, CameraService>,
+    app: AppHandle,
+) -> std::result::Result<Option<String>, String> {
+    Ok(service.end_session(&app).await)
+}
+
+/// Tile a session folder's images into a contact sheet JPEG, returning its path
+#[tauri::command]
+pub async fn tether_generate_contact_sheet(
+    service: tauri::State<'_, CameraService>,
+    app: AppHandle,
+    session_folder: String,
+    cols: u32,
+    thumb_size: u32,
+) -> std::result::Result<PathBuf, String> {
+    service.generate_contact_sheet(app, session_folder, cols, thumb_size).await
+}
+
+/// Apply a camera preset, rolling back on failure
+#[tauri::command]
+pub async fn tether_apply_preset(
+    service: tauri::State<'_, CameraService>,
+    preset: CameraPreset,
+) -> std::result::Result<(), String> {
+    service.apply_preset_atomic(preset).await
+}
+
+/// Reset the body's settings remotely; `confirm` must be true
+#[tauri::command]
+pub async fn tether_reset_camera_settings(
+    service: tauri::State<'_, CameraService>,
+    confirm: bool,
+) -> std::result::Result<(), String> {
+    service.reset_camera_settings(confirm).await
+}
+
+/// Apply the built-in default settings, returning the preset that was applied
+#[tauri::command]
+pub async fn tether_apply_default_settings(
+    service: tauri::State<'_, CameraService>,
+) -> std::result::Result<CameraPreset, String> {
+    service.apply_default_settings().await
+}
+
+/// Pause background polling so only explicit commands touch the camera
+#[tauri::command]
+pub async fn tether_lock_settings(
+    service: tauri::State<'_, CameraService>,
+) -> std::result::Result<(), String> {
+    service.lock_settings();
+    Ok(())
+}
+
+/// Resume background polling
+#[tauri::command]
+pub async fn tether_unlock_settings(
+    service: tauri::State<'_, CameraService>,
+) -> std::result::Result<(), String> {
+    service.unlock_settings();
+    Ok(())
+}
+
+/// List the RAW extensions the backend treats as RAW, so the UI can stay in sync
+#[tauri::command]
+pub fn tether_supported_raw_extensions() -> Vec<String> {
+    CameraService::supported_raw_extensions()
+}
+
+/// Check whether tethering is available on this machine (libgphoto2 loaded)
+#[tauri::command]
+pub async fn tether_backend_available() -> std::result::Result<bool, String> {
+    Ok(CameraService::backend_available().await)
+}
+
+/// Get the libgphoto2 version, supported and detected cameras, and why the backend failed to load
+#[tauri::command]
+pub async fn tether_get_backend_info(
+    service: tauri::State<'_, CameraService>,
+) -> std::result::Result<BackendInfo, String> {
+    Ok(service.get_backend_info().await)
+}
+
+/// Get the current tethering options
+#[tauri::command]
+pub async fn tether_get_options(
+    service: tauri::State<'_, CameraService>,
+) -> std::result::Result<TetherOptions, String> {
+    Ok(service.options.lock().await.clone())
+}
+
+/// Replace the tethering options
+#[tauri::command]
+pub async fn tether_set_options(
+    service: tauri::State<'_, CameraService>,
+    options: TetherOptions,
+) -> std::result::Result<(), String> {
+    service.set_options(options).await;
+    Ok(())
+}
+
 /// Set current download folder for camera button captures
 #[tauri::command]
 pub async fn tether_set_download_folder(
@@ -858,6 +7422,14 @@ pub async fn tether_set_download_folder(
     Ok(())
 }
 
+/// Show which config key each logical param resolved to on the connected camera
+#[tauri::command]
+pub async fn tether_get_param_key_map(
+    service: tauri::State<'_, CameraService>,
+) -> std::result::Result<std::collections::HashMap<String, Option<String>>, String> {
+    service.get_param_key_map().await
+}
+
 /// Get available choices for a camera configuration parameter
 #[tauri::command]
 pub async fn tether_get_config_choices(
@@ -867,6 +7439,165 @@ pub async fn tether_get_config_choices(
     service.get_config_choices(&config_key).await
 }
 
+/// Get ISO choices mapped to numeric values, marking expanded ones
+#[tauri::command]
+pub async fn tether_get_iso_choices(
+    service: tauri::State<'_, CameraService>,
+) -> std::result::Result<Vec<IsoValue>, String> {
+    service.get_iso_choices().await
+}
+
+/// Advanced: read a raw PTP device property the named settings don't cover
+#[tauri::command]
+pub async fn tether_get_ptp_property(
+    service: tauri::State<'_, CameraService>,
+    code: u16,
+) -> std::result::Result<Option<PtpValue>, String> {
+    service.get_ptp_property(code).await
+}
+
+/// Advanced: write a raw PTP device property the named settings don't cover
+#[tauri::command]
+pub async fn tether_set_ptp_property(
+    service: tauri::State<'_, CameraService>,
+    code: u16,
+    value: String,
+) -> std::result::Result<(), String> {
+    service.set_ptp_property(code, &value).await
+}
+
+/// Choose whether remote captures go to the card, the computer, or both
+#[tauri::command]
+pub async fn tether_set_capture_destination(
+    service: tauri::State<'_, CameraService>,
+    destination: CaptureDestination,
+) -> std::result::Result<(), String> {
+    service.set_capture_destination(destination).await
+}
+
+/// Get the body's time zone and DST settings
+#[tauri::command]
+pub async fn tether_get_time_zone(
+    service: tauri::State<'_, CameraService>,
+) -> std::result::Result<TimeZoneSettings, String> {
+    service.get_time_zone().await
+}
+
+/// Set the body's time zone and/or DST
+#[tauri::command]
+pub async fn tether_set_time_zone(
+    service: tauri::State<'_, CameraService>,
+    timezone: Option<String>,
+    dst: Option<bool>,
+) -> std::result::Result<(), String> {
+    service.set_time_zone(timezone, dst).await
+}
+
+/// Set the body's clock from the host, optionally with the host's time zone
+#[tauri::command]
+pub async fn tether_sync_camera_time(
+    service: tauri::State<'_, CameraService>,
+    set_timezone: Option<bool>,
+) -> std::result::Result<ClockSync, String> {
+    service.sync_camera_time(set_timezone.unwrap_or(false)).await
+}
+
+/// Read the selected AF point or zone
+#[tauri::command]
+pub async fn tether_get_af_point(
+    service: tauri::State<'_, CameraService>,
+) -> std::result::Result<Option<String>, String> {
+    service.get_af_point().await
+}
+
+/// Spot-meter at a normalized frame point and return the deviation in EV
+#[tauri::command]
+pub async fn tether_spot_meter(
+    service: tauri::State<'_, CameraService>,
+    x: f32,
+    y: f32,
+) -> std::result::Result<Option<f32>, String> {
+    service.spot_meter(x, y).await
+}
+
+/// Get the body's overheat warning level, if it reports one
+#[tauri::command]
+pub async fn tether_get_thermal_status(
+    service: tauri::State<'_, CameraService>,
+) -> std::result::Result<Option<ThermalStatus>, String> {
+    service.get_thermal_status().await
+}
+
+/// Get the camera's buffer status, if reported or inferable
+#[tauri::command]
+pub async fn tether_get_buffer_status(
+    service: tauri::State<'_, CameraService>,
+) -> std::result::Result<Option<BufferStatus>, String> {
+    service.get_buffer_status().await
+}
+
+/// Start a timelapse; frames arrive on `camera:captured`, the summary on `camera:timelapse_complete`
+#[tauri::command]
+pub async fn tether_start_timelapse(
+    service: tauri::State<'_, CameraService>,
+    app: AppHandle,
+    target_folder: Option<String>,
+    interval_secs: f64,
+    count: Option<u32>,
+    image_format: Option<String>,
+) -> std::result::Result<(), String> {
+    service.shared().start_timelapse(app, target_folder, interval_secs, count, image_format).await
+}
+
+/// Stop a running timelapse; its loop still emits `camera:timelapse_complete`
+#[tauri::command]
+pub async fn tether_stop_timelapse(
+    service: tauri::State<'_, CameraService>,
+) -> std::result::Result<IntervalSummary, String> {
+    service.stop_interval().await
+}
+
+/// Stop a running interval/timelapse and get its summary
+#[tauri::command]
+pub async fn tether_stop_interval(
+    service: tauri::State<'_, CameraService>,
+) -> std::result::Result<IntervalSummary, String> {
+    service.stop_interval().await
+}
+
+/// Put the camera into power-save mode
+#[tauri::command]
+pub async fn tether_camera_sleep(
+    service: tauri::State<'_, CameraService>,
+) -> std::result::Result<(), String> {
+    service.camera_sleep().await
+}
+
+/// Wake the camera from power-save mode
+#[tauri::command]
+pub async fn tether_camera_wake(
+    service: tauri::State<'_, CameraService>,
+) -> std::result::Result<(), String> {
+    service.camera_wake().await
+}
+
+/// Get the camera's own self-timer delay and the delays it supports
+#[tauri::command]
+pub async fn tether_get_self_timer(
+    service: tauri::State<'_, CameraService>,
+) -> std::result::Result<SelfTimer, String> {
+    service.get_self_timer().await
+}
+
+/// Set the camera's own self-timer delay in seconds (0 turns it off)
+#[tauri::command]
+pub async fn tether_set_self_timer(
+    service: tauri::State<'_, CameraService>,
+    seconds: u32,
+) -> std::result::Result<(), String> {
+    service.set_self_timer(seconds).await
+}
+
 /// Set a camera configuration parameter value
 #[tauri::command]
 pub async fn tether_set_config_value(
@@ -0,0 +1,60 @@
+//! Built-in continuous shooting rates for bodies that don't report one
+
+use super::sensor_dimensions::lookup_model;
+
+/// Rated continuous rate in frames per second with the mechanical shutter
+/// (electronic for bodies without one), for common tethering bodies
+pub const BURST_RATES: &[(&str, f32)] = &[
+    // Canon
+    ("Canon EOS R", 8.0),
+    ("Canon EOS RP", 5.0),
+    ("Canon EOS R3", 12.0),
+    ("Canon EOS R5", 12.0),
+    ("Canon EOS R5 Mark II", 12.0),
+    ("Canon EOS R6", 12.0),
+    ("Canon EOS R6 Mark II", 12.0),
+    ("Canon EOS R7", 15.0),
+    ("Canon EOS R8", 6.0),
+    ("Canon EOS R10", 15.0),
+    ("Canon EOS R50", 12.0),
+    ("Canon EOS 5D Mark IV", 7.0),
+    ("Canon EOS 6D Mark II", 6.5),
+    ("Canon EOS 90D", 10.0),
+    // Nikon
+    ("Nikon Z 5", 4.5),
+    ("Nikon Z 6", 12.0),
+    ("Nikon Z 6II", 14.0),
+    ("Nikon Z 6III", 14.0),
+    ("Nikon Z 7", 9.0),
+    ("Nikon Z 7II", 10.0),
+    ("Nikon Z 8", 20.0),
+    ("Nikon Z 9", 20.0),
+    ("Nikon Z f", 14.0),
+    ("Nikon D750", 6.5),
+    ("Nikon D780", 7.0),
+    ("Nikon D850", 7.0),
+    // Sony (gphoto2 reports the ILCE product code)
+    ("Sony ILCE-1", 10.0),
+    ("Sony ILCE-7M3", 10.0),
+    ("Sony ILCE-7M4", 10.0),
+    ("Sony ILCE-7RM4", 10.0),
+    ("Sony ILCE-7RM5", 10.0),
+    ("Sony ILCE-9M3", 120.0),
+    ("Sony ILCE-6700", 11.0),
+    // Fujifilm
+    ("Fujifilm X-T4", 15.0),
+    ("Fujifilm X-T5", 15.0),
+    ("Fujifilm X-H2", 15.0),
+    ("Fujifilm X-H2S", 15.0),
+    ("Fujifilm GFX100S", 5.0),
+    // Panasonic / OM System
+    ("Panasonic DC-S5", 7.0),
+    ("Panasonic DC-G9", 12.0),
+    ("Panasonic DC-GH6", 14.0),
+    ("Olympus E-M1 Mark III", 15.0),
+];
+
+/// Look up the rated continuous rate for a camera model string
+pub fn lookup(model: &str) -> Option<f32> {
+    lookup_model(BURST_RATES, model)
+}
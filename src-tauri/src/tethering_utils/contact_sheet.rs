@@ -0,0 +1,43 @@
+//! Contact sheet tiling for a session's previews
+
+use std::path::Path;
+
+use image::codecs::jpeg::JpegEncoder;
+use image::{DynamicImage, GenericImageView, Rgb, RgbImage};
+
+/// File name of the sheet inside the session folder; skipped when scanning for captures
+pub const CONTACT_SHEET_FILE_NAME: &str = "contact_sheet.jpg";
+
+/// Gap between cells and around the sheet, in pixels
+const GAP: u32 = 8;
+
+const BACKGROUND: Rgb<u8> = Rgb([30, 30, 30]);
+
+/// Tile thumbnails into a grid of `cols` columns, each fitted and centered in a square cell
+pub fn tile(thumbnails: &[DynamicImage], cols: u32, cell_size: u32) -> RgbImage {
+    let cols = cols.max(1).min(thumbnails.len().max(1) as u32);
+    let rows = (thumbnails.len() as u32).div_ceil(cols).max(1);
+    let width = cols * cell_size + (cols + 1) * GAP;
+    let height = rows * cell_size + (rows + 1) * GAP;
+
+    let mut sheet = RgbImage::from_pixel(width, height, BACKGROUND);
+    for (index, thumbnail) in thumbnails.iter().enumerate() {
+        let index = index as u32;
+        let fitted = thumbnail.thumbnail(cell_size, cell_size);
+        let (w, h) = fitted.dimensions();
+        let x = GAP + (index % cols) * (cell_size + GAP) + (cell_size - w) / 2;
+        let y = GAP + (index / cols) * (cell_size + GAP) + (cell_size - h) / 2;
+        image::imageops::overlay(&mut sheet, &fitted.to_rgb8(), x as i64, y as i64);
+    }
+    sheet
+}
+
+/// Write the sheet as a JPEG
+pub fn write_jpeg(sheet: &RgbImage, path: &Path) -> std::result::Result<(), String> {
+    let file = std::fs::File::create(path)
+        .map_err(|e| format!("Failed to create contact sheet: {}", e))?;
+    let mut writer = std::io::BufWriter::new(file);
+    sheet
+        .write_with_encoder(JpegEncoder::new_with_quality(&mut writer, 90))
+        .map_err(|e| format!("Failed to encode contact sheet: {}", e))
+}
@@ -0,0 +1,83 @@
+//! Preview metering and EV math for exposing to a target brightness and for bracketing
+//! Brightness is the mean of the frame's sRGB-encoded luma (0..1); corrections are worked out
+//! in linear light, so a mean that is half the target doesn't read as exactly one stop.
+
+/// Longest side of the frame the mean is taken on
+const METER_MAX_SIDE: u32 = 256;
+
+/// Mean luma of an encoded preview frame (usually JPEG), 0 for black and 1 for white
+pub fn mean_brightness(frame: &[u8]) -> std::result::Result<f32, String> {
+    let image = image::load_from_memory(frame)
+        .map_err(|e| format!("Failed to decode preview frame: {}", e))?;
+    let gray = image.thumbnail(METER_MAX_SIDE, METER_MAX_SIDE).to_luma8();
+
+    let histogram = imageproc::stats::histogram(&gray);
+    let (total, sum) = histogram.channels[0]
+        .iter()
+        .enumerate()
+        .fold((0u64, 0u64), |(total, sum), (level, count)| {
+            (total + *count as u64, sum + level as u64 * *count as u64)
+        });
+    if total == 0 {
+        return Err("Preview frame is empty".to_string());
+    }
+    Ok(sum as f32 / total as f32 / 255.0)
+}
+
+fn srgb_to_linear(value: f32) -> f32 {
+    if value <= 0.04045 {
+        value / 12.92
+    } else {
+        ((value + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Stops of exposure that move a frame with `mean` brightness to `target`
+pub fn ev_correction(mean: f32, target: f32) -> f32 {
+    // A black frame would ask for infinite stops; treat it as one level above black
+    let floor = 1.0 / 255.0;
+    let mean = srgb_to_linear(mean.clamp(floor, 1.0));
+    let target = srgb_to_linear(target.clamp(floor, 1.0));
+    (target / mean).log2()
+}
+
+/// Parse an exposure compensation label ("+1/3", "-1 2/3", "-0.7", "0") into stops
+pub fn parse_ev(label: &str) -> Option<f32> {
+    let trimmed = label.trim().trim_end_matches(|c: char| c.is_alphabetic() || c.is_whitespace());
+    let (sign, rest) = match trimmed.chars().next()? {
+        '-' => (-1.0, &trimmed[1..]),
+        '+' => (1.0, &trimmed[1..]),
+        _ => (1.0, trimmed),
+    };
+
+    let mut stops = 0.0;
+    for part in rest.split_whitespace() {
+        stops += match part.split_once('/') {
+            Some((numerator, denominator)) => {
+                let denominator = denominator.parse::<f32>().ok()?;
+                if denominator == 0.0 {
+                    return None;
+                }
+                numerator.parse::<f32>().ok()? / denominator
+            }
+            None => part.parse::<f32>().ok()?,
+        };
+    }
+    Some(sign * stops)
+}
+
+/// Parse a shutter speed label ("1/125", "1/8000s", "2.5\"", "30") into seconds; None for bulb
+pub fn parse_shutter_seconds(label: &str) -> Option<f32> {
+    let trimmed = label.trim().trim_end_matches(|c: char| c == '"' || c == 's' || c.is_whitespace());
+    let seconds = match trimmed.split_once('/') {
+        Some((numerator, denominator)) => {
+            let denominator = denominator.trim().parse::<f32>().ok()?;
+            if denominator == 0.0 {
+                return None;
+            }
+            numerator.trim().parse::<f32>().ok()? / denominator
+        }
+        None => trimmed.parse::<f32>().ok()?,
+    };
+    (seconds > 0.0).then_some(seconds)
+}
@@ -0,0 +1,75 @@
+//! Focus peaking overlay for live view frames
+//! Runs a Sobel edge detector on a downscaled frame and marks the strongest edges.
+
+use std::io::Cursor;
+
+use base64::{Engine as _, engine::general_purpose};
+use image::{GrayImage, ImageFormat, Rgba, RgbaImage};
+use serde::{Deserialize, Serialize};
+
+/// Longest side of the frame the edge detector runs on; the UI scales the overlay back up
+const PEAKING_MAX_SIDE: u32 = 320;
+
+/// Highlight color for in-focus edges
+const PEAKING_COLOR: Rgba<u8> = Rgba([255, 40, 40, 255]);
+
+/// Edges within this fraction of the frame's strongest gradient count as in focus
+const PEAKING_RELATIVE_THRESHOLD: f32 = 0.35;
+
+/// Minimum gradient so flat, noisy frames don't light up entirely
+const PEAKING_MIN_GRADIENT: u16 = 96;
+
+/// Focus peaking overlay for one live view frame
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PeakingOverlay {
+    pub frame_index: u64,
+    pub width: u32,
+    pub height: u32,
+    /// Transparent PNG with highlighted edge pixels, as a data URL
+    pub overlay: String,
+}
+
+/// Compute the edge mask for a frame, true where an edge is sharp enough to highlight
+fn peaking_mask(gray: &GrayImage) -> Vec<bool> {
+    let gradients = imageproc::gradients::sobel_gradients(gray);
+    let max_gradient = gradients.pixels().map(|p| p[0]).max().unwrap_or(0);
+    let threshold = ((max_gradient as f32 * (1.0 - PEAKING_RELATIVE_THRESHOLD)) as u16)
+        .max(PEAKING_MIN_GRADIENT);
+
+    gradients.pixels().map(|p| p[0] >= threshold).collect()
+}
+
+/// Build a peaking overlay from an encoded live view frame (usually JPEG)
+pub fn compute_overlay(frame: &[u8], frame_index: u64) -> std::result::Result<PeakingOverlay, String> {
+    let image = image::load_from_memory(frame)
+        .map_err(|e| format!("Failed to decode live view frame: {}", e))?;
+    let image = if image.width().max(image.height()) > PEAKING_MAX_SIDE {
+        image.thumbnail(PEAKING_MAX_SIDE, PEAKING_MAX_SIDE)
+    } else {
+        image
+    };
+
+    let gray = image.to_luma8();
+    let (width, height) = gray.dimensions();
+    let mask = peaking_mask(&gray);
+
+    let mut overlay = RgbaImage::new(width, height);
+    for (pixel, sharp) in overlay.pixels_mut().zip(mask) {
+        if sharp {
+            *pixel = PEAKING_COLOR;
+        }
+    }
+
+    let mut buffer = Cursor::new(Vec::new());
+    overlay
+        .write_to(&mut buffer, ImageFormat::Png)
+        .map_err(|e| format!("Failed to encode peaking overlay: {}", e))?;
+
+    Ok(PeakingOverlay {
+        frame_index,
+        width,
+        height,
+        overlay: format!("data:image/png;base64,{}", general_purpose::STANDARD.encode(buffer.get_ref())),
+    })
+}
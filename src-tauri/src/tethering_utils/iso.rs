@@ -0,0 +1,82 @@
+//! ISO choice parsing, including expanded values reported as labels (L, H1, Hi 0.7)
+
+use serde::{Deserialize, Serialize};
+
+/// A camera ISO choice with its effective numeric value
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IsoValue {
+    /// Original choice string, used for display and for setting the value back
+    pub label: String,
+    /// Effective ISO for sorting and EV math
+    pub iso: u32,
+    /// True for expanded values outside the native range
+    pub extended: bool,
+}
+
+/// Native range assumed when a camera reports only extended labels
+const FALLBACK_NATIVE_RANGE: (u32, u32) = (100, 6400);
+
+/// Parse a plain numeric ISO label ("100", "ISO 3200")
+fn parse_native(label: &str) -> Option<u32> {
+    let trimmed = label.trim().to_lowercase();
+    let trimmed = trimmed.strip_prefix("iso").unwrap_or(&trimmed).trim();
+    trimmed.parse::<u32>().ok()
+}
+
+/// Parse an extended label into (is_high, stops)
+/// Canon uses "L", "H", "H1", "H2"; Nikon uses "Lo 1", "Hi 0.3", "Hi 2"
+fn parse_extended(label: &str) -> Option<(bool, f32)> {
+    let lower = label.trim().to_lowercase();
+    let (is_high, rest) = if let Some(rest) = lower.strip_prefix("hi") {
+        (true, rest)
+    } else if let Some(rest) = lower.strip_prefix('h') {
+        (true, rest)
+    } else if let Some(rest) = lower.strip_prefix("lo") {
+        (false, rest)
+    } else if let Some(rest) = lower.strip_prefix('l') {
+        (false, rest)
+    } else {
+        return None;
+    };
+
+    let rest = rest.trim_matches(|c: char| c.is_whitespace() || c == '-' || c == '+');
+    let stops = if rest.is_empty() {
+        1.0
+    } else {
+        rest.parse::<f32>().ok()?
+    };
+
+    if stops > 0.0 { Some((is_high, stops)) } else { None }
+}
+
+/// Map a camera's ISO choices to numeric values, sorted ascending
+/// Extended labels are placed relative to the native range reported by the same body.
+/// Non-numeric choices such as "Auto" are left out.
+pub fn parse_iso_choices(choices: &[String]) -> Vec<IsoValue> {
+    let native: Vec<u32> = choices.iter().filter_map(|c| parse_native(c)).collect();
+    let (min_native, max_native) = match (native.iter().min(), native.iter().max()) {
+        (Some(min), Some(max)) => (*min, *max),
+        _ => FALLBACK_NATIVE_RANGE,
+    };
+
+    let mut values: Vec<IsoValue> = choices
+        .iter()
+        .filter_map(|label| {
+            if let Some(iso) = parse_native(label) {
+                return Some(IsoValue { label: label.clone(), iso, extended: false });
+            }
+
+            let (is_high, stops) = parse_extended(label)?;
+            let iso = if is_high {
+                max_native as f32 * 2f32.powf(stops)
+            } else {
+                min_native as f32 / 2f32.powf(stops)
+            };
+            Some(IsoValue { label: label.clone(), iso: iso.round() as u32, extended: true })
+        })
+        .collect();
+
+    values.sort_by_key(|v| v.iso);
+    values
+}
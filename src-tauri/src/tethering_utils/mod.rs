@@ -0,0 +1,9 @@
+pub mod burst_rate;
+pub mod contact_sheet;
+pub mod exposure_target;
+pub mod focus_peaking;
+pub mod iso;
+pub mod remote_preview;
+pub mod self_timer;
+pub mod sensor_dimensions;
+pub mod xmp;
@@ -0,0 +1,109 @@
+//! Small WebP/AVIF previews of captures for viewing over a slow link
+//! The image crate only encodes lossless WebP, so WebP meets its byte budget by downscaling
+//! alone; AVIF steps its quality down first and only downscales when that isn't enough.
+
+use std::io::Cursor;
+
+use base64::{Engine as _, engine::general_purpose};
+use image::codecs::avif::AvifEncoder;
+use image::{DynamicImage, ImageFormat};
+use serde::{Deserialize, Serialize};
+
+/// Longest side of the first attempt
+const START_MAX_SIDE: u32 = 1600;
+
+/// Previews are never shrunk below this; the last attempt is returned even if over budget
+const MIN_MAX_SIDE: u32 = 240;
+
+/// Each failed size is shrunk to this fraction for the next attempt
+const SHRINK_FACTOR: f32 = 0.75;
+
+/// AVIF qualities tried at each size, best first
+const AVIF_QUALITIES: &[u8] = &[80, 65, 50, 35];
+
+/// AVIF encoder speed (1-10); previews favour speed over compression
+const AVIF_SPEED: u8 = 8;
+
+/// Encoding of remote previews
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PreviewFormat {
+    Webp,
+    Avif,
+}
+
+impl PreviewFormat {
+    fn mime_type(&self) -> &'static str {
+        match self {
+            PreviewFormat::Webp => "image/webp",
+            PreviewFormat::Avif => "image/avif",
+        }
+    }
+}
+
+/// Remote preview format and the size each preview should fit in
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RemotePreviewSettings {
+    pub format: PreviewFormat,
+    pub max_bytes: usize,
+}
+
+/// An encoded remote preview for one capture
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RemotePreview {
+    /// Capture the preview was made from
+    pub file_path: String,
+    pub format: PreviewFormat,
+    pub width: u32,
+    pub height: u32,
+    pub bytes: usize,
+    /// Encoded preview as a data URL
+    pub data: String,
+}
+
+fn encode(image: &DynamicImage, format: PreviewFormat, quality: u8) -> std::result::Result<Vec<u8>, String> {
+    let rgb = DynamicImage::ImageRgb8(image.to_rgb8());
+    let mut buffer = Cursor::new(Vec::new());
+    match format {
+        PreviewFormat::Webp => rgb.write_to(&mut buffer, ImageFormat::WebP),
+        PreviewFormat::Avif => rgb.write_with_encoder(AvifEncoder::new_with_speed_quality(&mut buffer, AVIF_SPEED, quality)),
+    }
+    .map_err(|e| format!("Failed to encode {:?} preview: {}", format, e))?;
+    Ok(buffer.into_inner())
+}
+
+/// Encode `image` in the requested format, shrinking it until it fits `max_bytes`
+pub fn encode_within_budget(image: &DynamicImage, settings: &RemotePreviewSettings, file_path: &str) -> std::result::Result<RemotePreview, String> {
+    let qualities: &[u8] = match settings.format {
+        PreviewFormat::Avif => AVIF_QUALITIES,
+        PreviewFormat::Webp => &[100],
+    };
+    let mut max_side = image.width().max(image.height()).min(START_MAX_SIDE);
+
+    loop {
+        let scaled = if image.width().max(image.height()) > max_side {
+            image.thumbnail(max_side, max_side)
+        } else {
+            image.clone()
+        };
+        let last_size = max_side <= MIN_MAX_SIDE;
+
+        for (index, &quality) in qualities.iter().enumerate() {
+            let encoded = encode(&scaled, settings.format, quality)?;
+            if encoded.len() <= settings.max_bytes || (last_size && index == qualities.len() - 1) {
+                return Ok(RemotePreview {
+                    file_path: file_path.to_string(),
+                    format: settings.format,
+                    width: scaled.width(),
+                    height: scaled.height(),
+                    bytes: encoded.len(),
+                    data: format!("data:{};base64,{}", settings.format.mime_type(), general_purpose::STANDARD.encode(&encoded)),
+                });
+            }
+        }
+
+        max_side = ((max_side as f32 * SHRINK_FACTOR) as u32).max(MIN_MAX_SIDE);
+    }
+}
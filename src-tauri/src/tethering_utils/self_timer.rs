@@ -0,0 +1,52 @@
+//! Self-timer choice parsing for bodies that expose the timer as its own widget or as a drive mode
+
+use serde::{Deserialize, Serialize};
+
+/// A camera self-timer choice with its delay
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SelfTimerChoice {
+    /// Original choice string, used for display and for setting the value back
+    pub label: String,
+    /// Delay in seconds; 0 means the timer is off, None when the body doesn't say
+    pub seconds: Option<u32>,
+}
+
+/// Parse the first number in a timer label ("2 sec", "Timer 10s", "Self-timer 5")
+fn parse_seconds(label: &str) -> Option<u32> {
+    let digits: String = label
+        .chars()
+        .skip_while(|c| !c.is_ascii_digit())
+        .take_while(|c| c.is_ascii_digit())
+        .collect();
+    digits.parse::<u32>().ok()
+}
+
+/// Parse one choice; `dedicated` is true for a timer-only widget such as `selftimer`
+/// Drive mode widgets (capturemode/drivemode) mix timers with other modes, so only
+/// single shot (timer off) and plain timer entries are kept there.
+fn parse_timer_choice(label: &str, dedicated: bool) -> Option<SelfTimerChoice> {
+    let lower = label.trim().to_lowercase();
+    if lower == "off" || (!dedicated && lower.starts_with("single")) {
+        return Some(SelfTimerChoice { label: label.to_string(), seconds: Some(0) });
+    }
+    if !dedicated && (!lower.contains("timer") || lower.contains("continuous")) {
+        return None;
+    }
+    Some(SelfTimerChoice { label: label.to_string(), seconds: parse_seconds(&lower) })
+}
+
+/// Map a widget's choices to self-timer choices
+/// Returns an empty list when none of the choices is an actual timer delay.
+pub fn parse_timer_choices(choices: &[String], dedicated: bool) -> Vec<SelfTimerChoice> {
+    let parsed: Vec<SelfTimerChoice> = choices
+        .iter()
+        .filter_map(|choice| parse_timer_choice(choice, dedicated))
+        .collect();
+
+    if parsed.iter().any(|choice| choice.seconds != Some(0)) {
+        parsed
+    } else {
+        Vec::new()
+    }
+}
@@ -0,0 +1,128 @@
+//! Built-in sensor resolutions used when capture dimensions can't be detected
+
+/// Last-resort dimensions when neither the file nor the model table yields a size
+pub const DEFAULT_DIMENSIONS: (u32, u32) = (1920, 1080);
+
+/// Full-resolution still dimensions (width, height) for common tethering bodies
+pub const SENSOR_DIMENSIONS: &[(&str, (u32, u32))] = &[
+    // Canon
+    ("Canon EOS R", (6720, 4480)),
+    ("Canon EOS RP", (6240, 4160)),
+    ("Canon EOS R3", (6000, 4000)),
+    ("Canon EOS R5", (8192, 5464)),
+    ("Canon EOS R5 Mark II", (8192, 5464)),
+    ("Canon EOS R6", (5472, 3648)),
+    ("Canon EOS R6 Mark II", (6000, 4000)),
+    ("Canon EOS R7", (6960, 4640)),
+    ("Canon EOS R8", (6000, 4000)),
+    ("Canon EOS R10", (6000, 4000)),
+    ("Canon EOS R50", (6000, 4000)),
+    ("Canon EOS 5D Mark IV", (6720, 4480)),
+    ("Canon EOS 6D Mark II", (6240, 4160)),
+    ("Canon EOS 90D", (6960, 4640)),
+    // Nikon
+    ("Nikon Z 5", (6016, 4016)),
+    ("Nikon Z 6", (6048, 4024)),
+    ("Nikon Z 6II", (6048, 4024)),
+    ("Nikon Z 6III", (6048, 4032)),
+    ("Nikon Z 7", (8256, 5504)),
+    ("Nikon Z 7II", (8256, 5504)),
+    ("Nikon Z 8", (8256, 5504)),
+    ("Nikon Z 9", (8256, 5504)),
+    ("Nikon Z f", (6048, 4032)),
+    ("Nikon D750", (6016, 4016)),
+    ("Nikon D780", (6048, 4024)),
+    ("Nikon D850", (8256, 5504)),
+    // Sony (gphoto2 reports the ILCE product code)
+    ("Sony ILCE-1", (8640, 5760)),
+    ("Sony ILCE-7M3", (6000, 4000)),
+    ("Sony ILCE-7M4", (7008, 4672)),
+    ("Sony ILCE-7RM4", (9504, 6336)),
+    ("Sony ILCE-7RM5", (9504, 6336)),
+    ("Sony ILCE-9M3", (6000, 4000)),
+    ("Sony ILCE-6700", (6192, 4128)),
+    // Fujifilm
+    ("Fujifilm X-T4", (6240, 4160)),
+    ("Fujifilm X-T5", (7728, 5152)),
+    ("Fujifilm X-H2", (7728, 5152)),
+    ("Fujifilm X-H2S", (6240, 4160)),
+    ("Fujifilm GFX100S", (11648, 8736)),
+    // Panasonic / OM System
+    ("Panasonic DC-S5", (6000, 4000)),
+    ("Panasonic DC-G9", (5184, 3888)),
+    ("Panasonic DC-GH6", (5776, 4336)),
+    ("Olympus E-M1 Mark III", (5184, 3888)),
+];
+
+/// Lowercase and strip everything but letters and digits ("EOS R5" -> "eosr5")
+fn compact(s: &str) -> String {
+    s.chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+/// Positions in `compact(s)` where a word of `s` ends (before a space or at the end)
+fn word_ends(s: &str) -> Vec<usize> {
+    let mut ends = Vec::new();
+    let mut len = 0;
+    for c in s.chars() {
+        if c.is_ascii_alphanumeric() {
+            len += 1;
+        } else if c.is_whitespace() && ends.last() != Some(&len) {
+            ends.push(len);
+        }
+    }
+    ends.push(len);
+    ends
+}
+
+/// Check whether `needle` occurs in `haystack` and ends where a word of the model ends,
+/// so "eosr5" doesn't match "EOS R50" and "eosr" doesn't match "EOS Rebel T7i"
+fn contains_model(haystack: &str, word_ends: &[usize], needle: &str) -> bool {
+    haystack
+        .match_indices(needle)
+        .any(|(idx, _)| word_ends.contains(&(idx + needle.len())))
+}
+
+/// Look up the sensor resolution for a camera model string
+pub fn lookup(model: &str) -> Option<(u32, u32)> {
+    lookup_model(SENSOR_DIMENSIONS, model)
+}
+
+/// Look up a camera model string in a per-model table
+/// Brand prefixes are optional and the longest matching model wins,
+/// so "Canon EOS R5 Mark II" doesn't fall back to the plain R5 entry
+pub fn lookup_model<T: Copy>(table: &[(&str, T)], model: &str) -> Option<T> {
+    let model_compact = compact(model);
+    if model_compact.is_empty() {
+        return None;
+    }
+    let model_word_ends = word_ends(model);
+
+    table
+        .iter()
+        .filter_map(|(name, value)| {
+            // Match on the model part too, since gphoto2 doesn't always include the brand
+            let full = compact(name);
+            let without_brand = name
+                .split_once(' ')
+                .map(|(_, rest)| compact(rest))
+                .unwrap_or_else(|| full.clone());
+
+            if contains_model(&model_compact, &model_word_ends, &full)
+                || contains_model(&model_compact, &model_word_ends, &without_brand)
+            {
+                Some((without_brand.len(), *value))
+            } else {
+                None
+            }
+        })
+        .max_by_key(|(len, _)| *len)
+        .map(|(_, value)| value)
+}
+
+/// Model-specific fallback, then the generic default
+pub fn fallback_dimensions(model: &str) -> (u32, u32) {
+    lookup(model).unwrap_or(DEFAULT_DIMENSIONS)
+}
@@ -0,0 +1,107 @@
+//! Minimal XMP sidecar writer for tethered RAW captures
+//! Covers the basic fields Lightroom and darktable read on import
+
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Local};
+
+use crate::tethering::CameraParams;
+
+/// Escape text for use inside XML attribute and element content
+fn escape_xml(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Convert a camera value like "f/2.8", "1/125", "2.5\"" or "+0.7" into an XMP rational
+fn to_rational(value: &str) -> Option<String> {
+    let cleaned: String = value
+        .trim()
+        .trim_start_matches("f/")
+        .trim_start_matches('f')
+        .chars()
+        .filter(|c| c.is_ascii_digit() || *c == '.' || *c == '/' || *c == '-')
+        .collect();
+
+    if let Some((num, den)) = cleaned.split_once('/') {
+        let num: i64 = num.parse().ok()?;
+        let den: i64 = den.parse().ok()?;
+        return if den != 0 { Some(format!("{}/{}", num, den)) } else { None };
+    }
+
+    let number: f64 = cleaned.parse().ok()?;
+    Some(format!("{}/10", (number * 10.0).round() as i64))
+}
+
+/// Sidecar path using the Adobe convention (IMG_0001.CR3 -> IMG_0001.xmp)
+pub fn sidecar_path(file_path: &Path) -> PathBuf {
+    file_path.with_extension("xmp")
+}
+
+/// Build the XMP packet for a capture
+pub fn build_xmp(params: &CameraParams, rating: Option<u8>, capture_time: DateTime<Local>) -> String {
+    let timestamp = capture_time.format("%Y-%m-%dT%H:%M:%S%:z").to_string();
+    let mut attributes = vec![
+        format!("xmp:CreateDate=\"{}\"", timestamp),
+        format!("exif:DateTimeOriginal=\"{}\"", timestamp),
+        format!("tiff:Model=\"{}\"", escape_xml(&params.model)),
+    ];
+
+    if let Some(rating) = rating {
+        attributes.push(format!("xmp:Rating=\"{}\"", rating.min(5)));
+    }
+    if let Some(exposure) = to_rational(&params.shutter_speed) {
+        attributes.push(format!("exif:ExposureTime=\"{}\"", exposure));
+    }
+    if let Some(f_number) = to_rational(&params.aperture) {
+        attributes.push(format!("exif:FNumber=\"{}\"", f_number));
+    }
+    if let Some(bias) = params.exposure_compensation.as_deref().and_then(to_rational) {
+        attributes.push(format!("exif:ExposureBiasValue=\"{}\"", bias));
+    }
+
+    // ISO is an ordered list in the EXIF schema
+    let iso_element = params
+        .iso
+        .trim()
+        .parse::<u32>()
+        .map(|iso| {
+            format!(
+                "\n   <exif:ISOSpeedRatings>\n    <rdf:Seq>\n     <rdf:li>{}</rdf:li>\n    </rdf:Seq>\n   </exif:ISOSpeedRatings>\n  ",
+                iso
+            )
+        })
+        .unwrap_or_default();
+
+    format!(
+        r#"<?xpacket begin="" id="W5M0MpCehiHzreSzNTczkc9d"?>
+<x:xmpmeta xmlns:x="adobe:ns:meta/">
+ <rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#">
+  <rdf:Description rdf:about=""
+    xmlns:xmp="http://ns.adobe.com/xap/1.0/"
+    xmlns:exif="http://ns.adobe.com/exif/1.0/"
+    xmlns:tiff="http://ns.adobe.com/tiff/1.0/"
+    {}>{}</rdf:Description>
+ </rdf:RDF>
+</x:xmpmeta>
+<?xpacket end="w"?>
+"#,
+        attributes.join("\n    "),
+        iso_element
+    )
+}
+
+/// Write the XMP sidecar next to a capture
+pub fn write_sidecar(
+    file_path: &Path,
+    params: &CameraParams,
+    rating: Option<u8>,
+    capture_time: DateTime<Local>,
+) -> std::io::Result<PathBuf> {
+    let path = sidecar_path(file_path);
+    std::fs::write(&path, build_xmp(params, rating, capture_time))?;
+    Ok(path)
+}
@@ -86,6 +86,90 @@ export enum Invokes {
   TetherSetDownloadFolder = 'tether_set_download_folder',
   TetherGetConfigChoices = 'tether_get_config_choices',
   TetherSetConfigValue = 'tether_set_config_value',
+  TetherLockFocus = 'tether_lock_focus',
+  TetherUnlockFocus = 'tether_unlock_focus',
+  TetherStopAll = 'tether_stop_all',
+  TetherGetOptions = 'tether_get_options',
+  TetherSetOptions = 'tether_set_options',
+  TetherCancelAutoConnect = 'tether_cancel_auto_connect',
+  TetherGetIsoChoices = 'tether_get_iso_choices',
+  TetherGetParamKeyMap = 'tether_get_param_key_map',
+  TetherStartSession = 'tether_start_session',
+  TetherEndSession = 'tether_end_session',
+  TetherTriggerCapture = 'tether_trigger_capture',
+  TetherGetOrientation = 'tether_get_orientation',
+  TetherApplyPreset = 'tether_apply_preset',
+  TetherGetExposureMeter = 'tether_get_exposure_meter',
+  TetherGetCapabilities = 'tether_get_capabilities',
+  TetherLockSettings = 'tether_lock_settings',
+  TetherUnlockSettings = 'tether_unlock_settings',
+  TetherSupportedRawExtensions = 'tether_supported_raw_extensions',
+  TetherBackendAvailable = 'tether_backend_available',
+  TetherGetSelfTimer = 'tether_get_self_timer',
+  TetherSetSelfTimer = 'tether_set_self_timer',
+  TetherCameraSleep = 'tether_camera_sleep',
+  TetherCameraWake = 'tether_camera_wake',
+  TetherGetBufferStatus = 'tether_get_buffer_status',
+  TetherGetExposureProgram = 'tether_get_exposure_program',
+  TetherGetLensInfo = 'tether_get_lens_info',
+  TetherCaptureAsync = 'tether_capture_async',
+  TetherListSessionCaptures = 'tether_list_session_captures',
+  TetherGetReviewSettings = 'tether_get_review_settings',
+  TetherSetImageReview = 'tether_set_image_review',
+  TetherSetAutoRotate = 'tether_set_auto_rotate',
+  TetherCaptureNativeFocusBracket = 'tether_capture_native_focus_bracket',
+  TetherStopInterval = 'tether_stop_interval',
+  TetherGenerateContactSheet = 'tether_generate_contact_sheet',
+  TetherGetAspectRatio = 'tether_get_aspect_ratio',
+  TetherSetAspectRatio = 'tether_set_aspect_ratio',
+  TetherGetModeChoices = 'tether_get_mode_choices',
+  TetherSetShutterType = 'tether_set_shutter_type',
+  TetherGetRecentCaptures = 'tether_get_recent_captures',
+  TetherBenchmarkCapture = 'tether_benchmark_capture',
+  TetherGetThermalStatus = 'tether_get_thermal_status',
+  TetherSpotMeter = 'tether_spot_meter',
+  TetherListCameraFolder = 'tether_list_camera_folder',
+  TetherDownloadSpecific = 'tether_download_specific',
+  TetherGetCardTree = 'tether_get_card_tree',
+  TetherResetCameraSettings = 'tether_reset_camera_settings',
+  TetherApplyDefaultSettings = 'tether_apply_default_settings',
+  TetherCaptureCalibrationSet = 'tether_capture_calibration_set',
+  TetherGetQuietSettings = 'tether_get_quiet_settings',
+  TetherSetBeep = 'tether_set_beep',
+  TetherSetAfAssist = 'tether_set_af_assist',
+  TetherGetConnectionHistory = 'tether_get_connection_history',
+  TetherSetRemotePreview = 'tether_set_remote_preview',
+  TetherDofPreview = 'tether_dof_preview',
+  TetherGetExposureDelay = 'tether_get_exposure_delay',
+  TetherSetExposureDelay = 'tether_set_exposure_delay',
+  TetherDownloadLatest = 'tether_download_latest',
+  TetherVerifyParamAliases = 'tether_verify_param_aliases',
+  TetherCaptureWbBracket = 'tether_capture_wb_bracket',
+  TetherGetAfPoint = 'tether_get_af_point',
+  TetherCaptureIsoSweep = 'tether_capture_iso_sweep',
+  TetherGetPtpProperty = 'tether_get_ptp_property',
+  TetherSetPtpProperty = 'tether_set_ptp_property',
+  TetherCaptureAutoExposure = 'tether_capture_auto_exposure',
+  TetherEnumerateDevices = 'tether_enumerate_devices',
+  TetherGetLastThumbnail = 'tether_get_last_thumbnail',
+  TetherSetCaptureDestination = 'tether_set_capture_destination',
+  TetherGetBackendInfo = 'tether_get_backend_info',
+  TetherCapturePending = 'tether_capture_pending',
+  TetherConfirmCapture = 'tether_confirm_capture',
+  TetherDiscardCapture = 'tether_discard_capture',
+  TetherGetTimeZone = 'tether_get_time_zone',
+  TetherSetTimeZone = 'tether_set_time_zone',
+  TetherSyncCameraTime = 'tether_sync_camera_time',
+  TetherStartLiveview = 'tether_start_liveview',
+  TetherStopLiveview = 'tether_stop_liveview',
+  TetherDriveFocus = 'tether_drive_focus',
+  TetherAutofocus = 'tether_autofocus',
+  TetherStartTimelapse = 'tether_start_timelapse',
+  TetherStopTimelapse = 'tether_stop_timelapse',
+  TetherCaptureBracket = 'tether_capture_bracket',
+  TetherCaptureFocusStack = 'tether_capture_focus_stack',
+  TetherListCameras = 'tether_list_cameras',
+  TetherConnectNetwork = 'tether_connect_network',
   // Folder watching commands
   StartFolderWatcher = 'start_folder_watcher',
   StopFolderWatcher = 'stop_folder_watcher',